@@ -0,0 +1,49 @@
+//! Approximate equality for the unit tests, comparing coefficients
+//! pairwise against an absolute tolerance.
+
+use std::fmt::Debug;
+
+use cl2::Vector2;
+use clifford;
+use {Multivector, RealField, Vector};
+
+/// Anything whose coefficients can be compared as `f64`s.
+pub trait Coefficients: Debug {
+    fn coefficients(&self) -> Vec<f64>;
+}
+
+impl<T: RealField> Coefficients for Vector<T> {
+    fn coefficients(&self) -> Vec<f64> {
+        vec![self.x.to_f64(), self.y.to_f64(), self.z.to_f64()]
+    }
+}
+
+impl<T: RealField> Coefficients for Vector2<T> {
+    fn coefficients(&self) -> Vec<f64> {
+        vec![self.x.to_f64(), self.y.to_f64()]
+    }
+}
+
+impl<T: RealField> Coefficients for Multivector<T> {
+    fn coefficients(&self) -> Vec<f64> {
+        [self.scalar, self.e1, self.e2, self.e3, self.e12, self.e23, self.e31, self.e123]
+            .iter().map(|c| c.to_f64()).collect()
+    }
+}
+
+impl<const P: usize, const Q: usize, const R: usize, T: RealField> Coefficients for clifford::Multivector<P, Q, R, T> {
+    fn coefficients(&self) -> Vec<f64> {
+        self.coeffs().iter().map(|c| c.to_f64()).collect()
+    }
+}
+
+/// Asserts every coefficient of `actual` is within 1e-12 of `expected`.
+pub fn assert_close<A: Coefficients>(expected: A, actual: A) {
+    assert_close_within(expected, actual, 1e-12);
+}
+
+pub fn assert_close_within<A: Coefficients>(expected: A, actual: A, tolerance: f64) {
+    let close = expected.coefficients().iter().zip(actual.coefficients())
+        .all(|(e, a)| (e - a).abs() < tolerance);
+    assert!(close, "{:?} != {:?}", expected, actual);
+}
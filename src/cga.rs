@@ -0,0 +1,413 @@
+//! Conformal geometric algebra Cl(4,1) for spheres, circles and flats.
+//!
+//! The basis of `clifford::Cga3` is e1, e2, e3, e+ and e-, with the null
+//! vectors n₀ = ½(e- - e+) for the origin and n∞ = e- + e+ for the point at
+//! infinity. Objects built from points are stored in their direct (outer
+//! product) form, so a point X lies on an object A exactly when X ∧ A = 0.
+//! Intersections are the undual of the wedge of duals.
+
+use clifford;
+use {Dual, GeometricProduct, GradeInvolution, InnerProduct, Meet, RealField, Reverse, Ring, Rotor, Vector, WedgeProduct};
+
+/// `clifford::Cga3` over any scalar type.
+type Cga<T> = clifford::Multivector<4, 1, 0, T>;
+
+const E_PLUS: usize = 0b01000;
+const E_MINUS: usize = 0b10000;
+
+/// The null vector n₀ representing the origin.
+pub fn origin<T: RealField>() -> Cga<T> {
+    (Cga::blade(E_MINUS, T::one()) - Cga::blade(E_PLUS, T::one())).scale(T::from_f64(0.5))
+}
+
+/// The null vector n∞ representing the point at infinity.
+pub fn infinity<T: Ring>() -> Cga<T> {
+    Cga::blade(E_MINUS, T::one()) + Cga::blade(E_PLUS, T::one())
+}
+
+/// Embeds `x` as the null vector n₀ + x + ½x² n∞.
+pub fn up<T: RealField>(x: &Vector<T>) -> Cga<T> {
+    let sq = x.x * x.x + x.y * x.y + x.z * x.z;
+    origin() + euclidean(x) + infinity().scale(T::from_f64(0.5) * sq)
+}
+
+/// Projects a conformal point back to Euclidean space, dividing out the
+/// weight -X·n∞.
+pub fn down<T: RealField>(x: &Cga<T>) -> Vector<T> {
+    let w = -x.innerp(&infinity()).get(0);
+    Vector::new(x.get(0b001) / w, x.get(0b010) / w, x.get(0b100) / w)
+}
+
+fn euclidean<T: Ring>(x: &Vector<T>) -> Cga<T> {
+    Cga::from_vector(&[x.x, x.y, x.z, T::zero(), T::zero()])
+}
+
+fn meet<T: Ring>(a: &Cga<T>, b: &Cga<T>) -> Cga<T> {
+    a.dual().wedgep(&b.dual()).undual()
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Point<T = f64>(pub Cga<T>);
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct PointPair<T = f64>(pub Cga<T>);
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Circle<T = f64>(pub Cga<T>);
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Sphere<T = f64>(pub Cga<T>);
+
+/// A straight line, the flat through two points and infinity.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Line<T = f64>(pub Cga<T>);
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Plane<T = f64>(pub Cga<T>);
+
+/// A conformal transformation acting through V X V⁻¹, with X grade-involuted
+/// when V is odd.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Versor<T = f64>(pub Cga<T>);
+
+impl<T: RealField> Point<T> {
+    pub fn to_vector(&self) -> Vector<T> {
+        down(&self.0)
+    }
+}
+
+impl<'a, T: RealField> From<&'a Vector<T>> for Point<T> {
+    fn from(v: &'a Vector<T>) -> Self {
+        Point(up(v))
+    }
+}
+
+impl<T: RealField> From<Vector<T>> for Point<T> {
+    fn from(v: Vector<T>) -> Self {
+        Point::from(&v)
+    }
+}
+
+impl<T: Ring> PointPair<T> {
+    pub fn from_points(a: &Point<T>, b: &Point<T>) -> Self {
+        PointPair(a.0.wedgep(&b.0))
+    }
+}
+
+impl<T: RealField> PointPair<T> {
+    /// The two points, or `None` when the pair is imaginary (B² < 0).
+    /// For B = P ∧ Q they are recovered as (B ∓ √B²)(n∞ ⌋ B).
+    pub fn points(&self) -> Option<(Vector<T>, Vector<T>)> {
+        let sq = self.0.geop(&self.0).get(0);
+        if sq < T::zero() {
+            return None;
+        }
+        let root = Cga::scalar(sq.sqrt());
+        let tangent = infinity().innerp(&self.0);
+        Some((down(&(self.0.clone() - root.clone()).geop(&tangent)),
+            down(&(self.0.clone() + root).geop(&tangent))))
+    }
+}
+
+impl<T: Ring> Circle<T> {
+    pub fn from_points(a: &Point<T>, b: &Point<T>, c: &Point<T>) -> Self {
+        Circle(a.0.wedgep(&b.0).wedgep(&c.0))
+    }
+}
+
+impl<T: Ring> Sphere<T> {
+    pub fn from_points(a: &Point<T>, b: &Point<T>, c: &Point<T>, d: &Point<T>) -> Self {
+        Sphere(a.0.wedgep(&b.0).wedgep(&c.0).wedgep(&d.0))
+    }
+}
+
+impl<T: RealField> Sphere<T> {
+    pub fn new(center: &Vector<T>, radius: T) -> Self {
+        Sphere((up(center) - infinity().scale(T::from_f64(0.5) * radius * radius)).undual())
+    }
+
+    /// The dual sphere up(c) - ½r² n∞, scaled to unit weight.
+    fn normalized_dual(&self) -> Cga<T> {
+        let s = self.0.dual();
+        let w = -s.innerp(&infinity()).get(0);
+        s.scale(T::one() / w)
+    }
+
+    pub fn center(&self) -> Vector<T> {
+        down(&self.normalized_dual())
+    }
+
+    /// The signed squared radius ρ², negative for an imaginary sphere.
+    pub fn squared_radius(&self) -> T {
+        let s = self.normalized_dual();
+        s.innerp(&s).get(0)
+    }
+
+    /// The radius, or `None` for an imaginary sphere (ρ² < 0).
+    pub fn radius(&self) -> Option<T> {
+        let sq = self.squared_radius();
+        if sq < T::zero() { None } else { Some(sq.sqrt()) }
+    }
+}
+
+impl<T: Ring> Line<T> {
+    pub fn from_points(a: &Point<T>, b: &Point<T>) -> Self {
+        Line(a.0.wedgep(&b.0).wedgep(&infinity()))
+    }
+}
+
+impl<T: Ring> Plane<T> {
+    pub fn from_points(a: &Point<T>, b: &Point<T>, c: &Point<T>) -> Self {
+        Plane(a.0.wedgep(&b.0).wedgep(&c.0).wedgep(&infinity()))
+    }
+
+    /// The plane n·x = d.
+    pub fn new(normal: &Vector<T>, d: T) -> Self {
+        Plane((euclidean(normal) + infinity().scale(d)).undual())
+    }
+}
+
+impl<T: Ring> Versor<T> {
+    /// The versor applying `self` first and `next` afterwards.
+    pub fn then(&self, next: &Versor<T>) -> Versor<T> {
+        Versor(next.0.geop(&self.0))
+    }
+}
+
+impl<T: RealField> Versor<T> {
+    /// The translator 1 - ½ t n∞.
+    pub fn translation(t: &Vector<T>) -> Self {
+        Versor(Cga::scalar(T::one()) - euclidean(t).geop(&infinity()).scale(T::from_f64(0.5)))
+    }
+
+    /// Uniform scaling about the origin by `factor`, the dilator
+    /// cosh(λ/2) + sinh(λ/2) n∞ ∧ n₀ with λ = -ln(factor).
+    pub fn dilation(factor: T) -> Self {
+        let half = -factor.ln() / T::from_f64(2.0);
+        Versor(Cga::scalar(half.cosh()) + infinity().wedgep(&origin()).scale(half.sinh()))
+    }
+
+    /// Inversion in `sphere`, whose dual vector is the versor.
+    pub fn inversion(sphere: &Sphere<T>) -> Self {
+        Versor(sphere.normalized_dual())
+    }
+
+    /// Whether every even-grade coefficient is within `tolerance` of zero.
+    pub fn is_odd(&self, tolerance: T) -> bool {
+        self.0.coeffs().iter().enumerate().all(|(mask, c)| c.abs() <= tolerance || mask.count_ones() % 2 == 1)
+    }
+
+    /// V X V⁻¹, treating V as odd when its even part is within `tolerance`
+    /// of zero.
+    pub fn apply(&self, x: &Cga<T>, tolerance: T) -> Cga<T> {
+        let rev = self.0.reverse();
+        let norm = self.0.geop(&rev).get(0);
+        let x = if self.is_odd(tolerance) { x.involute() } else { x.clone() };
+        self.0.geop(&x).geop(&rev).scale(T::one() / norm)
+    }
+
+    pub fn apply_point(&self, p: &Point<T>, tolerance: T) -> Point<T> {
+        Point(self.apply(&p.0, tolerance))
+    }
+}
+
+/// A rotation about the origin.
+impl<T: Ring> From<Rotor<T>> for Versor<T> {
+    fn from(r: Rotor<T>) -> Self {
+        let mut mv = Cga::scalar(r.scalar);
+        mv.set(0b011, r.bivector.e12);
+        mv.set(0b110, r.bivector.e23);
+        mv.set(0b101, -r.bivector.e31);
+        Versor(mv)
+    }
+}
+
+impl<T: Ring> Meet for Sphere<T> {
+    type Output = Circle<T>;
+
+    fn meet(&self, other: &Sphere<T>) -> Circle<T> {
+        Circle(meet(&self.0, &other.0))
+    }
+}
+
+impl<T: Ring> Meet<Plane<T>> for Sphere<T> {
+    type Output = Circle<T>;
+
+    fn meet(&self, other: &Plane<T>) -> Circle<T> {
+        Circle(meet(&self.0, &other.0))
+    }
+}
+
+impl<T: Ring> Meet for Plane<T> {
+    type Output = Line<T>;
+
+    fn meet(&self, other: &Plane<T>) -> Line<T> {
+        Line(meet(&self.0, &other.0))
+    }
+}
+
+impl<T: Ring> Meet<Sphere<T>> for Line<T> {
+    type Output = PointPair<T>;
+
+    fn meet(&self, other: &Sphere<T>) -> PointPair<T> {
+        PointPair(meet(&self.0, &other.0))
+    }
+}
+
+impl<T: Ring> Meet<Plane<T>> for Circle<T> {
+    type Output = PointPair<T>;
+
+    fn meet(&self, other: &Plane<T>) -> PointPair<T> {
+        PointPair(meet(&self.0, &other.0))
+    }
+}
+
+impl<T: Ring> Meet<Sphere<T>> for Circle<T> {
+    type Output = PointPair<T>;
+
+    fn meet(&self, other: &Sphere<T>) -> PointPair<T> {
+        PointPair(meet(&self.0, &other.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use approx::assert_close_within;
+    use clifford::Cga3;
+    use std::f64::consts::PI;
+
+    fn point(x: f64, y: f64, z: f64) -> Point {
+        Point::from(Vector::new(x, y, z))
+    }
+
+    #[test]
+    fn test_up_down() {
+        let v = Vector::new(1.0, -2.0, 0.5);
+        let p = up(&v);
+
+        assert_close_within(Cga3::zero(), p.geop(&p), 1e-9);
+        assert_close_within(v, down(&p), 1e-9);
+        assert_close_within(v, down(&p.scale(3.0)), 1e-9);
+        assert_eq!(Cga3::scalar(-1.0), origin().innerp(&infinity()));
+    }
+
+    #[test]
+    fn test_imaginary_sphere() {
+        let center = Vector::new(1.0, 0.0, 0.0);
+        let imaginary = Sphere((up(&center) + infinity().scale(2.0)).undual());
+
+        assert!((4.0 + imaginary.squared_radius()).abs() < 1e-9);
+        assert_eq!(None, imaginary.radius());
+        assert!((4.0 - Sphere::new(&center, 2.0).squared_radius()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_sphere_from_points() {
+        let sphere = Sphere::from_points(&point(3.0, 0.0, 0.0), &point(1.0, 2.0, 0.0),
+            &point(1.0, 0.0, 2.0), &point(-1.0, 0.0, 0.0));
+
+        assert_close_within(Vector::new(1.0, 0.0, 0.0), sphere.center(), 1e-9);
+        assert!((2.0 - sphere.radius().unwrap()).abs() < 1e-9);
+        assert_close_within(Cga3::zero(), sphere.0.wedgep(&point(1.0, -2.0, 0.0).0), 1e-9);
+        assert_close_within(Sphere::new(&Vector::new(1.0, 0.0, 0.0), 2.0).center(), sphere.center(), 1e-9);
+    }
+
+    #[test]
+    fn test_flats_contain_points() {
+        let a = point(1.0, 0.0, 0.0);
+        let b = point(0.0, 1.0, 0.0);
+        let c = point(0.0, 0.0, 1.0);
+
+        let line = Line::from_points(&a, &b);
+        let plane = Plane::from_points(&a, &b, &c);
+        let circle = Circle::from_points(&a, &b, &c);
+
+        assert_close_within(Cga3::zero(), line.0.wedgep(&point(2.0, -1.0, 0.0).0), 1e-9);
+        assert_close_within(Cga3::zero(), plane.0.wedgep(&point(-1.0, 1.0, 1.0).0), 1e-9);
+        assert_close_within(Cga3::zero(), circle.0.wedgep(&PointPair::from_points(&a, &b).0), 1e-9);
+    }
+
+    #[test]
+    fn test_line_meets_sphere() {
+        let line = Line::from_points(&point(-5.0, 0.0, 0.0), &point(5.0, 0.0, 0.0));
+        let sphere = Sphere::new(&Vector::new(0.0, 0.0, 0.0), 2.0);
+
+        let (p, q) = line.meet(&sphere).points().unwrap();
+        let (p, q) = if p.x < q.x { (p, q) } else { (q, p) };
+
+        assert_close_within(Vector::new(-2.0, 0.0, 0.0), p, 1e-9);
+        assert_close_within(Vector::new(2.0, 0.0, 0.0), q, 1e-9);
+    }
+
+    #[test]
+    fn test_missed_sphere_is_imaginary() {
+        let line = Line::from_points(&point(-5.0, 3.0, 0.0), &point(5.0, 3.0, 0.0));
+        let sphere = Sphere::new(&Vector::new(0.0, 0.0, 0.0), 2.0);
+
+        assert_eq!(None, line.meet(&sphere).points());
+    }
+
+    #[test]
+    fn test_single_precision() {
+        let sphere = Sphere::<f32>::new(&Vector::new(1.0, 0.0, 0.0), 2.0);
+        let moved = Versor::<f32>::dilation(2.0).apply_point(&Point::from(Vector::new(1.0, 2.0, 3.0)), 1e-6);
+
+        assert!((2.0 - sphere.radius().unwrap()).abs() < 1e-5);
+        assert_close_within(Vector::new(2.0, 4.0, 6.0), moved.to_vector(), 1e-4);
+    }
+
+    #[test]
+    fn test_spheres_meet_in_circle() {
+        let a = Sphere::new(&Vector::new(0.0, 0.0, 0.0), 1.0);
+        let b = Sphere::new(&Vector::new(1.0, 0.0, 0.0), 1.0);
+
+        let circle = a.meet(&b);
+        let plane = Plane::new(&Vector::new(0.0, 1.0, 0.0), 0.0);
+
+        assert_close_within(Cga3::zero(), circle.0.wedgep(&point(0.5, 0.0, 0.75f64.sqrt()).0), 1e-9);
+        let (p, q) = circle.meet(&plane).points().unwrap();
+        assert!((p.z.abs() - 0.75f64.sqrt()).abs() < 1e-9);
+        assert!((p.z + q.z).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_planes_meet_in_line() {
+        let a = Plane::new(&Vector::new(1.0, 0.0, 0.0), 1.0);
+        let b = Plane::new(&Vector::new(0.0, 1.0, 0.0), 2.0);
+
+        assert_close_within(Cga3::zero(), a.meet(&b).0.wedgep(&point(1.0, 2.0, 7.0).0), 1e-9);
+    }
+
+    #[test]
+    fn test_translation_and_dilation() {
+        let p = point(1.0, 2.0, 3.0);
+
+        assert_close_within(Vector::new(2.0, 2.0, 1.0),
+            Versor::translation(&Vector::new(1.0, 0.0, -2.0)).apply_point(&p, 1e-12).to_vector(), 1e-9);
+        assert_close_within(Vector::new(2.0, 4.0, 6.0), Versor::dilation(2.0).apply_point(&p, 1e-12).to_vector(), 1e-9);
+    }
+
+    #[test]
+    fn test_inversion() {
+        let sphere = Sphere::new(&Vector::new(1.0, 0.0, 0.0), 2.0);
+        let inversion = Versor::inversion(&sphere);
+
+        assert_close_within(Vector::new(5.0, 0.0, 0.0), inversion.apply_point(&point(2.0, 0.0, 0.0), 1e-12).to_vector(), 1e-9);
+        assert_close_within(Vector::new(1.0, 2.0, 0.0), inversion.apply_point(&point(1.0, 2.0, 0.0), 1e-12).to_vector(), 1e-9);
+
+        let rounded = Versor(inversion.0.clone() + Cga3::scalar(1e-15));
+        assert!(rounded.is_odd(1e-12));
+        assert!(!rounded.is_odd(0.0));
+        assert_close_within(Vector::new(5.0, 0.0, 0.0), rounded.apply_point(&point(2.0, 0.0, 0.0), 1e-12).to_vector(), 1e-9);
+    }
+
+    #[test]
+    fn test_rotation_then_translation() {
+        let rotation = Versor::from(Rotor::from_axis_angle(&Vector::new(0.0, 0.0, 1.0), PI / 2.0));
+        let versor = rotation.then(&Versor::translation(&Vector::new(0.0, 0.0, 1.0)));
+
+        assert_close_within(Vector::new(0.0, 1.0, 1.0), versor.apply_point(&point(1.0, 0.0, 0.0), 1e-12).to_vector(), 1e-9);
+    }
+}
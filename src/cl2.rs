@@ -0,0 +1,401 @@
+//! The plane algebra Cl(2,0). Its even subalgebra, scalars plus multiples
+//! of e12 with e12² = -1, is isomorphic to the complex numbers.
+
+use {Angle, Conjugate, GeometricProduct, GradeInvolution, InnerProduct, Inverse, Magnitude, RealField, Reverse, Ring, Scalar, WedgeProduct};
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Vector2<T = f64> {
+    pub x: T,
+    pub y: T
+}
+
+/// An oriented area in the plane, a multiple of e12.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct Bivector2<T = f64> {
+    pub e12: T
+}
+
+/// An element of the even subalgebra, scalar + e12, corresponding to the
+/// complex number scalar + i e12.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct Even2<T = f64> {
+    pub scalar: T,
+    pub e12: T
+}
+
+/// A unit even element rotating vectors through R v R̃.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Rotor2<T = f64> {
+    pub scalar: T,
+    pub e12: T
+}
+
+impl<T: Ring> Vector2<T> {
+    pub fn new(x: T, y: T) -> Self {
+        Vector2 {
+            x,
+            y
+        }
+    }
+}
+
+impl<T: Ring> Bivector2<T> {
+    pub fn new(e12: T) -> Self {
+        Bivector2 {
+            e12
+        }
+    }
+}
+
+impl<T: Ring> Even2<T> {
+    pub fn new(scalar: T, e12: T) -> Self {
+        Even2 {
+            scalar,
+            e12
+        }
+    }
+}
+
+impl<T: RealField> Even2<T> {
+    /// The argument of the corresponding complex number.
+    pub fn arg(&self) -> T {
+        self.e12.atan2(self.scalar)
+    }
+}
+
+impl<T: Ring> Rotor2<T> {
+    pub fn identity() -> Self {
+        Rotor2 {
+            scalar: T::one(),
+            e12: T::zero()
+        }
+    }
+
+    /// The rotor applying `self` first and `next` afterwards.
+    pub fn then(&self, next: &Rotor2<T>) -> Rotor2<T> {
+        let even = Even2::from(*next).geop(&Even2::from(*self));
+        Rotor2 {
+            scalar: even.scalar,
+            e12: even.e12
+        }
+    }
+
+    /// R v R̃, which in the plane reduces to v R̃².
+    pub fn rotate(&self, v: &Vector2<T>) -> Vector2<T> {
+        let rev = Even2::from(self.reverse());
+        v.geop(&rev.geop(&rev))
+    }
+}
+
+impl<T: RealField> Rotor2<T> {
+    /// Counter-clockwise rotation by `angle` radians, cos(θ/2) - e12 sin(θ/2).
+    pub fn from_angle(angle: T) -> Self {
+        let (sin, cos) = (angle / T::from_f64(2.0)).sin_cos();
+        Rotor2 {
+            scalar: cos,
+            e12: -sin
+        }
+    }
+
+    /// The rotation taking the direction of `from` onto the direction of `to`.
+    pub fn from_vectors(from: &Vector2<T>, to: &Vector2<T>) -> Self {
+        let cross = from.wedgep(to).e12;
+        Rotor2::from_angle(cross.atan2(from.innerp(to).value))
+    }
+
+    pub fn angle(&self) -> T {
+        -T::from_f64(2.0) * self.e12.atan2(self.scalar)
+    }
+}
+
+impl<T: Ring> Reverse for Vector2<T> {
+    fn reverse(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Ring> GradeInvolution for Vector2<T> {
+    fn involute(&self) -> Self {
+        Vector2::new(-self.x, -self.y)
+    }
+}
+
+impl<T: Ring> Conjugate for Vector2<T> {
+    fn conjugate(&self) -> Self {
+        self.involute()
+    }
+}
+
+impl<T: Ring> Reverse for Bivector2<T> {
+    fn reverse(&self) -> Self {
+        Bivector2::new(-self.e12)
+    }
+}
+
+impl<T: Ring> GradeInvolution for Bivector2<T> {
+    fn involute(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Ring> Conjugate for Bivector2<T> {
+    fn conjugate(&self) -> Self {
+        self.reverse()
+    }
+}
+
+/// Reversion of an even element is complex conjugation.
+impl<T: Ring> Reverse for Even2<T> {
+    fn reverse(&self) -> Self {
+        Even2::new(self.scalar, -self.e12)
+    }
+}
+
+impl<T: Ring> GradeInvolution for Even2<T> {
+    fn involute(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Ring> Conjugate for Even2<T> {
+    fn conjugate(&self) -> Self {
+        self.reverse()
+    }
+}
+
+impl<T: Ring> Reverse for Rotor2<T> {
+    fn reverse(&self) -> Self {
+        Rotor2 {
+            scalar: self.scalar,
+            e12: -self.e12
+        }
+    }
+}
+
+impl<T: Ring> GradeInvolution for Rotor2<T> {
+    fn involute(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Ring> Conjugate for Rotor2<T> {
+    fn conjugate(&self) -> Self {
+        self.reverse()
+    }
+}
+
+impl<T: RealField> Magnitude<T> for Vector2<T> {
+    fn mag(&self) -> T {
+        (self.x * self.x + self.y * self.y).sqrt()
+    }
+}
+
+impl<T: RealField> Magnitude<T> for Bivector2<T> {
+    fn mag(&self) -> T {
+        self.e12.abs()
+    }
+}
+
+impl<T: RealField> Magnitude<T> for Even2<T> {
+    fn mag(&self) -> T {
+        (self.scalar * self.scalar + self.e12 * self.e12).sqrt()
+    }
+}
+
+impl<T: RealField> Magnitude<T> for Rotor2<T> {
+    fn mag(&self) -> T {
+        Even2::from(*self).mag()
+    }
+}
+
+/// R̃ / (R R̃), which is just the reverse for a unit rotor.
+impl<T: RealField> Inverse for Rotor2<T> {
+    fn inverse(&self) -> Option<Self> {
+        let norm = self.scalar * self.scalar + self.e12 * self.e12;
+        if norm.is_zero() {
+            return None;
+        }
+        Some(Rotor2 {
+            scalar: self.scalar / norm,
+            e12: -self.e12 / norm
+        })
+    }
+}
+
+impl<T: RealField> Angle<Vector2<T>, T> for Vector2<T> {
+    fn angle(&self, other: &Vector2<T>) -> T {
+        (self.innerp(other).value / (self.mag() * other.mag())).acos()
+    }
+}
+
+impl<T: Ring> InnerProduct for Vector2<T> {
+    type Output = Scalar<T>;
+
+    fn innerp(&self, other: &Vector2<T>) -> Scalar<T> {
+        Scalar {
+            value: self.x * other.x + self.y * other.y
+        }
+    }
+}
+
+impl<T: Ring> WedgeProduct for Vector2<T> {
+    type Output = Bivector2<T>;
+
+    fn wedgep(&self, other: &Vector2<T>) -> Bivector2<T> {
+        Bivector2 {
+            e12: self.x * other.y - self.y * other.x
+        }
+    }
+}
+
+impl<T: Ring> GeometricProduct for Vector2<T> {
+    type Output = Even2<T>;
+
+    fn geop(&self, other: &Vector2<T>) -> Even2<T> {
+        Even2::new(self.innerp(other).value, self.wedgep(other).e12)
+    }
+}
+
+impl<T: Ring> GeometricProduct<Even2<T>> for Vector2<T> {
+    type Output = Vector2<T>;
+
+    fn geop(&self, other: &Even2<T>) -> Vector2<T> {
+        Vector2::new(self.x * other.scalar - self.y * other.e12, self.y * other.scalar + self.x * other.e12)
+    }
+}
+
+impl<T: Ring> GeometricProduct for Even2<T> {
+    type Output = Even2<T>;
+
+    fn geop(&self, other: &Even2<T>) -> Even2<T> {
+        Even2::new(self.scalar * other.scalar - self.e12 * other.e12,
+            self.scalar * other.e12 + self.e12 * other.scalar)
+    }
+}
+
+impl<T: Ring> From<(T, T)> for Even2<T> {
+    fn from((re, im): (T, T)) -> Self {
+        Even2::new(re, im)
+    }
+}
+
+impl<T: Ring> From<Even2<T>> for (T, T) {
+    fn from(z: Even2<T>) -> Self {
+        (z.scalar, z.e12)
+    }
+}
+
+/// The complex number e1 v = x + y e12.
+impl<T: Ring> From<Vector2<T>> for Even2<T> {
+    fn from(v: Vector2<T>) -> Self {
+        Even2::new(v.x, v.y)
+    }
+}
+
+/// The vector e1 z, inverting the conversion from `Vector2`.
+impl<T: Ring> From<Even2<T>> for Vector2<T> {
+    fn from(z: Even2<T>) -> Self {
+        Vector2::new(z.scalar, z.e12)
+    }
+}
+
+impl<T: Ring> From<Bivector2<T>> for Even2<T> {
+    fn from(b: Bivector2<T>) -> Self {
+        Even2::new(T::zero(), b.e12)
+    }
+}
+
+impl<T: Ring> From<Rotor2<T>> for Even2<T> {
+    fn from(r: Rotor2<T>) -> Self {
+        Even2::new(r.scalar, r.e12)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use approx::assert_close;
+    use std::f64::consts::PI;
+    use Vector;
+
+    fn cos_angle<V>(a: &V, b: &V) -> f64
+        where V: Magnitude + InnerProduct<Output = Scalar>
+    {
+        a.innerp(b).value / (a.mag() * b.mag())
+    }
+
+    #[test]
+    fn test_vector2_products() {
+        let vec1 = Vector2::new(1.0, 2.0);
+        let vec2 = Vector2::new(3.0, -1.0);
+
+        assert_eq!(1.0, vec1.innerp(&vec2).value);
+        assert_eq!(Bivector2::new(-7.0), vec1.wedgep(&vec2));
+        assert_eq!(Even2::new(1.0, -7.0), vec1.geop(&vec2));
+        assert_eq!(PI / 2.0, Vector2::new(1.0, 0.0).angle(&Vector2::new(0.0, 1.0)));
+    }
+
+    #[test]
+    fn test_generic_over_dimension() {
+        let cos2 = cos_angle(&Vector2::new(1.0, 1.0), &Vector2::new(1.0, 0.0));
+        let cos3 = cos_angle(&Vector::new(1.0, 1.0, 0.0), &Vector::new(1.0, 0.0, 0.0));
+
+        assert_eq!(cos2, cos3);
+    }
+
+    #[test]
+    fn test_complex_correspondence() {
+        let z = Even2::from((1.0, 2.0));
+        let w = Even2::from((3.0, -1.0));
+
+        assert_eq!((5.0, 5.0), <(f64, f64)>::from(z.geop(&w)));
+        assert_eq!(Even2::new(-1.0, 0.0), Even2::from(Bivector2::new(1.0)).geop(&Even2::from(Bivector2::new(1.0))));
+        assert_eq!(5.0f64.sqrt(), z.mag());
+        assert_eq!(Vector2::new(1.0, 2.0), Vector2::from(z));
+    }
+
+    #[test]
+    fn test_involutions() {
+        let vec1 = Vector2::new(1.0, 2.0);
+        let vec2 = Vector2::new(3.0, -1.0);
+
+        assert_eq!(vec2.geop(&vec1), vec1.geop(&vec2).reverse());
+        assert_eq!(Even2::from((1.0, -2.0)), Even2::from((1.0, 2.0)).conjugate());
+        assert_eq!(Vector2::new(-1.0, -2.0), vec1.involute());
+        assert_eq!(Bivector2::new(7.0), vec1.wedgep(&vec2).conjugate());
+    }
+
+    #[test]
+    fn test_rotor2_rotate() {
+        let rotor = Rotor2::from_angle(PI / 2.0);
+
+        assert_close(Vector2::new(0.0, 1.0), rotor.rotate(&Vector2::new(1.0, 0.0)));
+        assert!((PI / 2.0 - rotor.angle()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_rotor2_from_vectors() {
+        let from = Vector2::new(1.0, 1.0);
+        let to = Vector2::new(-2.0, 0.0);
+
+        let rotor = Rotor2::from_vectors(&from, &to);
+
+        assert_close(Vector2::new(-2.0f64.sqrt(), 0.0), rotor.rotate(&from));
+    }
+
+    #[test]
+    fn test_rotor2_then_inverse() {
+        let first = Rotor2::from_angle(0.3);
+        let second = Rotor2::from_angle(0.5);
+        let v = Vector2::new(2.0, 1.0);
+
+        assert_close(second.rotate(&first.rotate(&v)), first.then(&second).rotate(&v));
+        assert_close(v, first.inverse().unwrap().rotate(&first.rotate(&v)));
+        let scaled = Rotor2 { scalar: 2.0 * first.scalar, e12: 2.0 * first.e12 };
+        let identity = scaled.then(&scaled.inverse().unwrap());
+        assert!((1.0 - identity.scalar).abs() < 1e-12 && identity.e12.abs() < 1e-12);
+        assert_eq!(None, Rotor2 { scalar: 0.0, e12: 0.0 }.inverse());
+        assert!((0.8 - first.then(&second).angle()).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,808 @@
+//! Clifford algebras Cl(p,q,r) of arbitrary signature.
+//!
+//! The basis vectors are numbered so that the first `P` square to +1, the
+//! next `Q` to -1 and the last `R` to 0. A basis blade is identified by the
+//! bitmask of the basis vectors it contains, with the factors in increasing
+//! order, so e13 is `0b101` and the scalar is `0`.
+
+use std::ops::{Add, BitOr, BitXor, Mul, Neg, Sub};
+
+use {exceeds, Conjugate, Dual, GeometricProduct, Grade, GradeInvolution, InnerProduct, InnerProducts, Inverse, Magnitude, RealField, Reverse, Ring, WedgeProduct};
+
+pub type Cl2 = Multivector<2, 0, 0>;
+pub type Cl3 = Multivector<3, 0, 0>;
+pub type Cl4 = Multivector<4, 0, 0>;
+pub type Sta = Multivector<1, 3, 0>;
+pub type Pga3 = Multivector<3, 0, 1>;
+pub type Cga3 = Multivector<4, 1, 0>;
+
+/// Sign picked up by moving the factors of blade `b` past those of blade
+/// `a` into canonical order in the product `a b`.
+pub fn reordering_sign(a: usize, b: usize) -> f64 {
+    let mut a = a >> 1;
+    let mut swaps = 0;
+    while a != 0 {
+        swaps += (a & b).count_ones();
+        a >>= 1;
+    }
+    if swaps % 2 == 0 { 1.0 } else { -1.0 }
+}
+
+/// A multivector of Cl(P,Q,R), holding one coefficient per basis blade.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Multivector<const P: usize, const Q: usize, const R: usize, T = f64> {
+    coeffs: Vec<T>
+}
+
+impl<const P: usize, const Q: usize, const R: usize, T: Ring> Multivector<P, Q, R, T> {
+    pub const DIM: usize = P + Q + R;
+    pub const SIZE: usize = 1 << (P + Q + R);
+
+    pub fn zero() -> Self {
+        Multivector {
+            coeffs: vec![T::zero(); Self::SIZE]
+        }
+    }
+
+    pub fn scalar(value: T) -> Self {
+        Self::blade(0, value)
+    }
+
+    /// `value` times the basis blade with the given bitmask.
+    pub fn blade(mask: usize, value: T) -> Self {
+        let mut mv = Self::zero();
+        mv.coeffs[mask] = value;
+        mv
+    }
+
+    /// The unit pseudoscalar I, the product of all basis vectors in order.
+    pub fn pseudoscalar() -> Self {
+        Self::blade(Self::SIZE - 1, T::one())
+    }
+
+    /// The `i`th basis vector, counting from zero.
+    pub fn basis_vector(i: usize) -> Self {
+        Self::blade(1 << i, T::one())
+    }
+
+    /// A grade-1 element with the given coefficient for each basis vector.
+    pub fn from_vector(components: &[T]) -> Self {
+        assert_eq!(Self::DIM, components.len(), "expected one component per basis vector");
+        let mut mv = Self::zero();
+        for (i, c) in components.iter().enumerate() {
+            mv.coeffs[1 << i] = *c;
+        }
+        mv
+    }
+
+    /// Builds a multivector from all 2^n coefficients, indexed by bitmask.
+    pub fn from_coeffs(coeffs: Vec<T>) -> Self {
+        assert_eq!(Self::SIZE, coeffs.len(), "expected one coefficient per basis blade");
+        Multivector {
+            coeffs
+        }
+    }
+
+    pub fn coeffs(&self) -> &[T] {
+        &self.coeffs
+    }
+
+    pub fn get(&self, mask: usize) -> T {
+        self.coeffs[mask]
+    }
+
+    pub fn set(&mut self, mask: usize, value: T) {
+        self.coeffs[mask] = value;
+    }
+
+    /// The square of the `i`th basis vector.
+    pub fn metric(i: usize) -> f64 {
+        if i < P {
+            1.0
+        } else if i < P + Q {
+            -1.0
+        } else {
+            0.0
+        }
+    }
+
+    /// Geometric product of two basis blades: the sign (including metric
+    /// factors from repeated vectors, so possibly zero) and the result mask.
+    pub fn basis_product(a: usize, b: usize) -> (f64, usize) {
+        let mut sign = reordering_sign(a, b);
+        let mut common = a & b;
+        let mut i = 0;
+        while common != 0 {
+            if common & 1 == 1 {
+                sign *= Self::metric(i);
+            }
+            common >>= 1;
+            i += 1;
+        }
+        (sign, a ^ b)
+    }
+
+    pub fn scale(&self, factor: T) -> Self {
+        Multivector {
+            coeffs: self.coeffs.iter().map(|c| *c * factor).collect()
+        }
+    }
+
+    /// Negates the grade-k parts for which `flip(k)` holds.
+    fn negate_grades<F>(&self, flip: F) -> Self
+        where F: Fn(u32) -> bool
+    {
+        let mut mv = self.clone();
+        for (mask, c) in mv.coeffs.iter_mut().enumerate() {
+            if flip(mask.count_ones()) {
+                *c = -*c;
+            }
+        }
+        mv
+    }
+
+    /// The right complement, mapping each basis blade b to the blade b̄ with
+    /// b ∧ b̄ equal to the unit pseudoscalar. Unlike a dual through the
+    /// pseudoscalar's inverse it also exists for degenerate metrics.
+    pub fn complement(&self) -> Self {
+        let full = Self::SIZE - 1;
+        let mut mv = Self::zero();
+        for (mask, c) in self.coeffs.iter().enumerate() {
+            mv.coeffs[full ^ mask] = c.signed(reordering_sign(mask, full ^ mask));
+        }
+        mv
+    }
+
+    /// Inverse of `complement`.
+    pub fn uncomplement(&self) -> Self {
+        let full = Self::SIZE - 1;
+        let mut mv = Self::zero();
+        for (mask, c) in self.coeffs.iter().enumerate() {
+            mv.coeffs[full ^ mask] = c.signed(reordering_sign(full ^ mask, mask));
+        }
+        mv
+    }
+
+    /// The regressive product A ∨ B, the complement of the wedge of the
+    /// complements.
+    pub fn regressive(&self, other: &Self) -> Self {
+        self.complement().wedgep(&other.complement()).uncomplement()
+    }
+
+    /// Sums `f(a, b)` times the blade product over all coefficient pairs
+    /// whose grades satisfy `keep`.
+    fn product<F>(&self, other: &Self, keep: F) -> Self
+        where F: Fn(u32, u32, u32) -> bool
+    {
+        let mut mv = Self::zero();
+        for (a, ca) in self.coeffs.iter().enumerate().filter(|&(_, c)| !c.is_zero()) {
+            for (b, cb) in other.coeffs.iter().enumerate().filter(|&(_, c)| !c.is_zero()) {
+                let (sign, mask) = Self::basis_product(a, b);
+                if sign != 0.0 && keep(a.count_ones(), b.count_ones(), mask.count_ones()) {
+                    mv.coeffs[mask] = mv.coeffs[mask] + (*ca * *cb).signed(sign);
+                }
+            }
+        }
+        mv
+    }
+}
+
+impl<const P: usize, const Q: usize, const R: usize, T: Ring> GeometricProduct for Multivector<P, Q, R, T> {
+    type Output = Multivector<P, Q, R, T>;
+
+    fn geop(&self, other: &Self) -> Self {
+        self.product(other, |_, _, _| true)
+    }
+}
+
+impl<const P: usize, const Q: usize, const R: usize, T: Ring> WedgeProduct for Multivector<P, Q, R, T> {
+    type Output = Multivector<P, Q, R, T>;
+
+    fn wedgep(&self, other: &Self) -> Self {
+        self.product(other, |ga, gb, g| g == ga + gb)
+    }
+}
+
+/// The left contraction, see `InnerProducts::left_contraction`.
+impl<const P: usize, const Q: usize, const R: usize, T: Ring> InnerProduct for Multivector<P, Q, R, T> {
+    type Output = Multivector<P, Q, R, T>;
+
+    fn innerp(&self, other: &Self) -> Self {
+        self.left_contraction(other)
+    }
+}
+
+impl<const P: usize, const Q: usize, const R: usize, T: Ring> InnerProducts for Multivector<P, Q, R, T> {
+    type Output = Multivector<P, Q, R, T>;
+
+    fn left_contraction(&self, other: &Self) -> Self {
+        self.product(other, |ga, gb, g| gb >= ga && g == gb - ga)
+    }
+
+    fn right_contraction(&self, other: &Self) -> Self {
+        self.product(other, |ga, gb, g| ga >= gb && g == ga - gb)
+    }
+
+    fn scalar_product(&self, other: &Self) -> Self {
+        self.product(other, |_, _, g| g == 0)
+    }
+
+    fn hestenes(&self, other: &Self) -> Self {
+        self.product(other, |ga, gb, g| ga > 0 && gb > 0 && g == ga.max(gb) - ga.min(gb))
+    }
+
+    fn fat_dot(&self, other: &Self) -> Self {
+        self.product(other, |ga, gb, g| g == ga.max(gb) - ga.min(gb))
+    }
+}
+
+impl<const P: usize, const Q: usize, const R: usize, T: Ring> Reverse for Multivector<P, Q, R, T> {
+    fn reverse(&self) -> Self {
+        self.negate_grades(|k| k % 4 == 2 || k % 4 == 3)
+    }
+}
+
+impl<const P: usize, const Q: usize, const R: usize, T: Ring> GradeInvolution for Multivector<P, Q, R, T> {
+    fn involute(&self) -> Self {
+        self.negate_grades(|k| k % 2 == 1)
+    }
+}
+
+impl<const P: usize, const Q: usize, const R: usize, T: Ring> Conjugate for Multivector<P, Q, R, T> {
+    fn conjugate(&self) -> Self {
+        self.negate_grades(|k| k % 4 == 1 || k % 4 == 2)
+    }
+}
+
+impl<const P: usize, const Q: usize, const R: usize, T: Ring + PartialOrd> Grade<T> for Multivector<P, Q, R, T> {
+    fn grade(&self, k: usize) -> Self {
+        let mut mv = self.clone();
+        for (mask, c) in mv.coeffs.iter_mut().enumerate() {
+            if mask.count_ones() as usize != k {
+                *c = T::zero();
+            }
+        }
+        mv
+    }
+
+    fn grades(&self, tolerance: T) -> Vec<usize> {
+        (0..=Self::DIM).filter(|&k| {
+            self.coeffs.iter().enumerate()
+                .any(|(mask, &c)| mask.count_ones() as usize == k && exceeds(c, tolerance))
+        }).collect()
+    }
+
+    /// A homogeneous k-vector is a blade exactly when it satisfies the
+    /// Plücker relations (β ⌋ A) ∧ A = 0 for every basis (k-1)-blade β.
+    /// Decomposability does not depend on the metric, so the contraction
+    /// here is the Euclidean one and null blades are recognised as well.
+    fn is_blade(&self, tolerance: T) -> bool {
+        let grades = self.grades(tolerance);
+        if grades.len() > 1 {
+            return false;
+        }
+        let k = match grades.first() {
+            Some(&k) if k > 1 => k,
+            _ => return true
+        };
+        (0..Self::SIZE).filter(|beta| beta.count_ones() as usize == k - 1).all(|beta| {
+            let mut contracted = Self::zero();
+            for (mask, &c) in self.coeffs.iter().enumerate() {
+                if mask & beta == beta {
+                    contracted.coeffs[mask ^ beta] = c.signed(reordering_sign(beta, mask));
+                }
+            }
+            contracted.wedgep(self).grades(tolerance).is_empty()
+        })
+    }
+
+    /// A versor has a single parity, a non-zero scalar A Ã, and maps every
+    /// vector to a vector under Â x Ã.
+    fn is_versor(&self, tolerance: T) -> bool {
+        let grades = self.grades(tolerance);
+        if grades.is_empty() || grades.iter().any(|k| k % 2 != grades[0] % 2) {
+            return false;
+        }
+        let rev = self.reverse();
+        let norm = self.geop(&rev);
+        if norm.grades(tolerance) != vec![0] {
+            return false;
+        }
+        let involuted = self.involute();
+        (0..Self::DIM).all(|i| {
+            let image = involuted.geop(&Self::basis_vector(i)).geop(&rev);
+            image.grades(tolerance).iter().all(|&k| k == 1)
+        })
+    }
+}
+
+/// Duality through I⁻¹ = Ĩ / (IĨ), where IĨ is the product of the squares
+/// of the basis vectors. In a degenerate algebra I has no inverse and the
+/// dual vanishes, so `complement` should be used instead.
+impl<const P: usize, const Q: usize, const R: usize, T: Ring> Dual for Multivector<P, Q, R, T> {
+    type Output = Multivector<P, Q, R, T>;
+
+    fn dual(&self) -> Self {
+        let norm = (0..Self::DIM).map(Self::metric).product();
+        self.geop(&Self::pseudoscalar().reverse().scale(T::one().signed(norm)))
+    }
+
+    fn undual(&self) -> Self {
+        self.geop(&Self::pseudoscalar())
+    }
+
+    fn hodge(&self) -> Self {
+        self.reverse().undual()
+    }
+}
+
+/// Blades and versors take the fast path Ã / (AÃ), which holds whenever AÃ
+/// is a non-zero scalar; non-scalar parts of AÃ within a few ulps of its
+/// scalar part are taken to be rounding error. Any other element is
+/// inverted by solving A X = 1 as a linear system in the 2ⁿ coefficients
+/// of X, which is `None` when that system is singular.
+impl<const P: usize, const Q: usize, const R: usize, T: RealField> Inverse for Multivector<P, Q, R, T> {
+    fn inverse(&self) -> Option<Self> {
+        let rev = self.reverse();
+        let norm = self.geop(&rev);
+        let scalar = norm.get(0);
+        let tolerance = scalar.abs() * T::epsilon() * T::from_f64(Self::SIZE as f64);
+        if !scalar.is_zero() && !norm.coeffs.iter().skip(1).any(|&c| exceeds(c, tolerance)) {
+            return Some(rev.scale(T::one() / scalar));
+        }
+        self.solve_inverse()
+    }
+}
+
+impl<const P: usize, const Q: usize, const R: usize, T: RealField> Multivector<P, Q, R, T> {
+    /// Solves A X = 1 by Gaussian elimination with partial pivoting on the
+    /// matrix of left multiplication by A, whose column b is A e_b. As for
+    /// the dense `::Multivector`, an element is singular only when a pivot
+    /// is exactly zero, so nearly null elements still have an inverse.
+    fn solve_inverse(&self) -> Option<Self> {
+        let n = Self::SIZE;
+        let mut rows = vec![vec![T::zero(); n + 1]; n];
+        for b in 0..n {
+            let column = self.geop(&Self::blade(b, T::one()));
+            for (a, row) in rows.iter_mut().enumerate() {
+                row[b] = column.coeffs[a];
+            }
+        }
+        rows[0][n] = T::one();
+
+        for col in 0..n {
+            let mut pivot = col;
+            for r in col + 1..n {
+                if rows[r][col].abs() > rows[pivot][col].abs() {
+                    pivot = r;
+                }
+            }
+            if rows[pivot][col].is_zero() {
+                return None;
+            }
+            rows.swap(col, pivot);
+            let pivot_row = rows[col].clone();
+            for (r, row) in rows.iter_mut().enumerate() {
+                if r != col {
+                    let factor = row[col] / pivot_row[col];
+                    for (c, p) in row[col..].iter_mut().zip(&pivot_row[col..]) {
+                        *c = *c - factor * *p;
+                    }
+                }
+            }
+        }
+        Some(Multivector { coeffs: rows.iter().enumerate().map(|(i, row)| row[n] / row[i]).collect() })
+    }
+}
+
+impl<const P: usize, const Q: usize, const R: usize, T: RealField> Multivector<P, Q, R, T> {
+    /// Right division A B⁻¹, the X solving X B = A.
+    pub fn div_right(&self, b: &Self) -> Option<Self> {
+        b.inverse().map(|inv| self.geop(&inv))
+    }
+
+    /// Left division B⁻¹ A, the X solving B X = A.
+    pub fn div_left(&self, b: &Self) -> Option<Self> {
+        b.inverse().map(|inv| inv.geop(self))
+    }
+
+    /// The exponential exp(A), meant for bivectors. When A² is a scalar the
+    /// series closes: cos α + A sin α / α for A² = -α², as for Euclidean
+    /// planes, cosh α + A sinh α / α for A² = α², as for boosts, and 1 + A
+    /// for A² = 0 exactly, as for PGA translations. Other elements, such as the
+    /// non-simple bivectors of four or more dimensions, fall back to the
+    /// power series.
+    pub fn exp(&self) -> Self {
+        let sq = self.geop(self);
+        let s = sq.get(0);
+        let tolerance = T::epsilon().sqrt() * (T::one() + s.abs());
+        if sq.coeffs.iter().skip(1).any(|&c| exceeds(c, tolerance)) {
+            return self.exp_series();
+        }
+        if s < T::zero() {
+            let alpha = (-s).sqrt();
+            Self::scalar(alpha.cos()) + self.scale(alpha.sin() / alpha)
+        } else if s > T::zero() {
+            let alpha = s.sqrt();
+            Self::scalar(alpha.cosh()) + self.scale(alpha.sinh() / alpha)
+        } else {
+            Self::scalar(T::one()) + self.clone()
+        }
+    }
+
+    /// Sums the power series of exp(A / 2ᵏ), with k chosen to make the
+    /// argument small, and squares the result k times.
+    fn exp_series(&self) -> Self {
+        let mut largest = T::zero();
+        for c in &self.coeffs {
+            if c.abs() > largest {
+                largest = c.abs();
+            }
+        }
+        let mut halvings = 0;
+        let mut scale = T::one();
+        while largest * scale > T::from_f64(0.5) {
+            scale = scale / T::from_f64(2.0);
+            halvings += 1;
+        }
+        let a = self.scale(scale);
+        let mut term = Self::scalar(T::one());
+        let mut sum = term.clone();
+        for k in 1..32 {
+            term = term.geop(&a).scale(T::one() / T::from_f64(k as f64));
+            sum = sum + term.clone();
+        }
+        for _ in 0..halvings {
+            sum = sum.geop(&sum);
+        }
+        sum
+    }
+}
+
+/// The metric norm sqrt(|⟨A Ã⟩₀|), which is zero for null elements.
+impl<const P: usize, const Q: usize, const R: usize, T: RealField> Magnitude<T> for Multivector<P, Q, R, T> {
+    fn mag(&self) -> T {
+        self.geop(&self.reverse()).get(0).abs().sqrt()
+    }
+}
+
+impl<const P: usize, const Q: usize, const R: usize, T: Ring> Add for Multivector<P, Q, R, T> {
+    type Output = Multivector<P, Q, R, T>;
+
+    fn add(self, other: Self) -> Self {
+        Multivector {
+            coeffs: self.coeffs.iter().zip(other.coeffs.iter()).map(|(a, b)| *a + *b).collect()
+        }
+    }
+}
+
+impl<const P: usize, const Q: usize, const R: usize, T: Ring> Sub for Multivector<P, Q, R, T> {
+    type Output = Multivector<P, Q, R, T>;
+
+    fn sub(self, other: Self) -> Self {
+        Multivector {
+            coeffs: self.coeffs.iter().zip(other.coeffs.iter()).map(|(a, b)| *a - *b).collect()
+        }
+    }
+}
+
+impl<const P: usize, const Q: usize, const R: usize, T: Ring> Neg for Multivector<P, Q, R, T> {
+    type Output = Multivector<P, Q, R, T>;
+
+    fn neg(self) -> Self {
+        self.scale(-T::one())
+    }
+}
+
+/// `*`, `^` and `|` as the geometric, wedge and inner products, for owned
+/// and borrowed operands.
+macro_rules! product_op {
+    ($Op:ident, $op:ident, $method:ident) => {
+        impl<const P: usize, const Q: usize, const R: usize, T: Ring> $Op for Multivector<P, Q, R, T> {
+            type Output = Multivector<P, Q, R, T>;
+
+            fn $op(self, other: Self) -> Self {
+                self.$method(&other)
+            }
+        }
+
+        impl<'a, 'b, const P: usize, const Q: usize, const R: usize, T: Ring> $Op<&'b Multivector<P, Q, R, T>>
+            for &'a Multivector<P, Q, R, T>
+        {
+            type Output = Multivector<P, Q, R, T>;
+
+            fn $op(self, other: &'b Multivector<P, Q, R, T>) -> Multivector<P, Q, R, T> {
+                self.$method(other)
+            }
+        }
+    }
+}
+
+product_op!(Mul, mul, geop);
+product_op!(BitXor, bitxor, wedgep);
+product_op!(BitOr, bitor, innerp);
+
+impl<const P: usize, const Q: usize, const R: usize, T: Ring> Mul<T> for Multivector<P, Q, R, T> {
+    type Output = Multivector<P, Q, R, T>;
+
+    fn mul(self, k: T) -> Self {
+        self.scale(k)
+    }
+}
+
+impl<T: Ring> From<::Multivector<T>> for Multivector<3, 0, 0, T> {
+    fn from(m: ::Multivector<T>) -> Self {
+        Multivector::from_coeffs(vec![m.scalar, m.e1, m.e2, m.e12, m.e3, -m.e31, m.e23, m.e123])
+    }
+}
+
+impl<T: Ring> From<Multivector<3, 0, 0, T>> for ::Multivector<T> {
+    fn from(m: Multivector<3, 0, 0, T>) -> Self {
+        ::Multivector::new(m.get(0b000), m.get(0b001), m.get(0b010), m.get(0b100),
+            m.get(0b011), m.get(0b110), -m.get(0b101), m.get(0b111))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use approx::{assert_close, assert_close_within};
+    use Vector;
+
+    #[test]
+    fn test_reordering_sign() {
+        assert_eq!(1.0, reordering_sign(0b001, 0b010));
+        assert_eq!(-1.0, reordering_sign(0b010, 0b001));
+        assert_eq!(1.0, reordering_sign(0b011, 0b100));
+        assert_eq!(1.0, reordering_sign(0b100, 0b011));
+        assert_eq!(-1.0, reordering_sign(0b010, 0b101));
+    }
+
+    #[test]
+    fn test_signature_squares() {
+        let t = Sta::basis_vector(0);
+        let x = Sta::basis_vector(1);
+        let e0 = Pga3::basis_vector(3);
+
+        assert_eq!(Sta::scalar(1.0), t.geop(&t));
+        assert_eq!(Sta::scalar(-1.0), x.geop(&x));
+        assert_eq!(Pga3::zero(), e0.geop(&e0));
+    }
+
+    #[test]
+    fn test_anticommuting_vectors() {
+        let e1 = Cl4::basis_vector(0);
+        let e4 = Cl4::basis_vector(3);
+
+        assert_eq!(Cl4::blade(0b1001, 1.0), e1.geop(&e4));
+        assert_eq!(Cl4::blade(0b1001, -1.0), e4.geop(&e1));
+    }
+
+    #[test]
+    fn test_matches_euclidean_3d() {
+        let a = ::Multivector::new(1.0, 2.0, -1.0, 0.5, 3.0, -2.0, 1.5, 4.0);
+        let b = ::Multivector::new(-2.0, 0.5, 1.0, 2.0, -1.0, 3.0, 0.25, -1.0);
+
+        let expected = a.geop(&b);
+        let actual = ::Multivector::from(Cl3::from(a).geop(&Cl3::from(b)));
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_wedge_and_inner() {
+        let a = Cl3::from_vector(&[1.0, 2.0, 0.0]);
+        let b = Cl3::from_vector(&[0.0, 1.0, 3.0]);
+
+        let va = Vector::new(1.0, 2.0, 0.0);
+        let vb = Vector::new(0.0, 1.0, 3.0);
+
+        assert_eq!(Cl3::from(::Multivector::from(va.wedgep(&vb))), a.wedgep(&b));
+        assert_eq!(Cl3::scalar(va.innerp(&vb).value), a.innerp(&b));
+        assert_eq!(a.geop(&b), a.innerp(&b) + a.wedgep(&b));
+    }
+
+    #[test]
+    fn test_operators() {
+        let a = Cl3::from_vector(&[1.0, 2.0, 0.0]);
+        let b = Cl3::from_vector(&[0.0, 1.0, 3.0]);
+
+        assert_eq!(a.geop(&b), &a * &b);
+        assert_eq!(a.wedgep(&b), &a ^ &b);
+        assert_eq!((&a | &b) + (&a ^ &b), a.clone() * b.clone());
+        assert_eq!(a.scale(2.0), a * 2.0);
+    }
+
+    #[test]
+    fn test_regressive() {
+        let e12 = Cl3::blade(0b011, 1.0);
+        let e23 = Cl3::blade(0b110, 1.0);
+        let e2 = Cl3::basis_vector(1);
+
+        assert_eq!(e12, e12.complement().uncomplement());
+        assert_eq!(Cl3::blade(0b111, 1.0), e12.wedgep(&e12.complement()));
+        assert_eq!(e2, e12.regressive(&e23));
+    }
+
+    #[test]
+    fn test_involution_laws() {
+        let a = Cga3::from_coeffs((0..32).map(|i| (i as f64 * 0.37).sin()).collect());
+        let b = Cga3::from_coeffs((0..32).map(|i| (i as f64 * 0.71).cos()).collect());
+
+        assert_close(b.reverse().geop(&a.reverse()), a.geop(&b).reverse());
+        assert_close(a.involute().geop(&b.involute()), a.geop(&b).involute());
+        assert_close(b.conjugate().geop(&a.conjugate()), a.geop(&b).conjugate());
+        assert_eq!(a.reverse().involute(), a.conjugate());
+    }
+
+    #[test]
+    fn test_matches_euclidean_involutions() {
+        let m = ::Multivector::new(1.0, 2.0, -1.0, 0.5, 3.0, -2.0, 1.5, 4.0);
+
+        assert_eq!(Cl3::from(m.reverse()), Cl3::from(m).reverse());
+        assert_eq!(Cl3::from(m.involute()), Cl3::from(m).involute());
+        assert_eq!(Cl3::from(m.conjugate()), Cl3::from(m).conjugate());
+    }
+
+    #[test]
+    fn test_matches_euclidean_inner_products() {
+        let a = ::Multivector::new(1.0, 2.0, -1.0, 0.5, 3.0, -2.0, 1.5, 4.0);
+        let b = ::Multivector::new(-2.0, 1.0, 0.5, 3.0, -1.0, 2.0, 0.5, -3.0);
+        let (ca, cb) = (Cl3::from(a), Cl3::from(b));
+
+        assert_eq!(Cl3::from(a.left_contraction(&b)), ca.left_contraction(&cb));
+        assert_eq!(Cl3::from(a.right_contraction(&b)), ca.right_contraction(&cb));
+        assert_eq!(Cl3::from(a.scalar_product(&b)), ca.scalar_product(&cb));
+        assert_eq!(Cl3::from(a.hestenes(&b)), ca.hestenes(&cb));
+        assert_eq!(Cl3::from(a.fat_dot(&b)), ca.fat_dot(&cb));
+    }
+
+    #[test]
+    fn test_contraction_duality() {
+        let a = Sta::from_vector(&[1.0, 2.0, 0.0, 3.0]);
+        let b = Sta::blade(0b0011, 2.0) + Sta::blade(0b1010, -1.0);
+
+        assert_eq!(a.left_contraction(&b), b.reverse().right_contraction(&a.reverse()).reverse());
+    }
+
+    #[test]
+    fn test_dual() {
+        let m = ::Multivector::new(1.0, 2.0, -1.0, 0.5, 3.0, -2.0, 1.5, 4.0);
+        let a = Sta::from_vector(&[1.0, 2.0, 0.0, 3.0]);
+
+        assert_eq!(Cl3::from(m.dual()), Cl3::from(m).dual());
+        assert_eq!(Cl3::from(m.hodge()), Cl3::from(m).hodge());
+        assert_eq!(a, a.dual().undual());
+        assert_eq!(a, a.undual().dual());
+        assert_eq!(Pga3::zero(), Pga3::basis_vector(0).dual());
+    }
+
+    #[test]
+    fn test_inverse() {
+        let a = Sta::from_vector(&[1.0, 2.0, 0.0, 3.0]);
+        let b = Sta::from_vector(&[2.0, 0.0, 1.0, 0.0]);
+        let versor = a.geop(&b);
+        let one = Sta::scalar(1.0);
+
+        assert_close(one.clone(), a.geop(&a.inverse().unwrap()));
+        assert_close(one.clone(), versor.geop(&versor.inverse().unwrap()));
+        assert_close(b.clone(), a.geop(&b.div_left(&a).unwrap()));
+        assert_close(a.clone(), a.div_right(&b).unwrap().geop(&b));
+    }
+
+    #[test]
+    fn test_general_inverse() {
+        let a = Cl3::scalar(2.0) + Cl3::basis_vector(0);
+        let m = ::Multivector::new(1.0, 2.0, -1.0, 0.5, 3.0, -2.0, 1.5, 4.0);
+
+        assert_close(Cl3::scalar(2.0 / 3.0) - Cl3::basis_vector(0).scale(1.0 / 3.0), a.inverse().unwrap());
+        assert_close(Cl3::from(m.inverse().unwrap()), Cl3::from(m).inverse().unwrap());
+
+        let b = Cl3::from(m);
+        assert_close(b.clone(), a.geop(&b.div_left(&a).unwrap()));
+        assert_close(b.clone(), b.div_right(&a).unwrap().geop(&a));
+
+        let nearly_null = ::Multivector::new(1.0, 1.0 - 1e-9, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0);
+        assert!(nearly_null.inverse().is_some());
+        let inverse = Cl3::from(nearly_null).inverse().unwrap();
+        assert_close_within(Cl3::scalar(1.0), Cl3::from(nearly_null).geop(&inverse), 1e-6);
+
+        let nearly_scalar = Cl3::scalar(1.0) + Cl3::basis_vector(0).scale(1e-9);
+        assert_close(Cl3::scalar(1.0) - Cl3::basis_vector(0).scale(1e-9), nearly_scalar.inverse().unwrap());
+        assert_close(Cl3::scalar(1.0), nearly_scalar.geop(&nearly_scalar.inverse().unwrap()));
+
+        let mixed = Sta::scalar(1.0) + Sta::blade(0b0011, 0.5) + Sta::blade(0b1111, 2.0);
+        let one = Sta::scalar(1.0);
+        assert_close(one, mixed.geop(&mixed.inverse().unwrap()));
+    }
+
+    #[test]
+    fn test_non_invertible() {
+        let null = Sta::from_vector(&[1.0, 1.0, 0.0, 0.0]);
+
+        assert_eq!(None, null.inverse());
+        assert_eq!(None, Pga3::basis_vector(3).inverse());
+        assert_eq!(None, (Cl3::scalar(1.0) + Cl3::basis_vector(0)).inverse());
+        assert_eq!(None, (Pga3::scalar(1.0) + Pga3::basis_vector(3)).geop(&Pga3::basis_vector(3)).inverse());
+        assert_eq!(None, Cl3::zero().inverse());
+    }
+
+    #[test]
+    fn test_exp_matches_rotor() {
+        let plane = ::Bivector::new(0.3, -0.2, 0.5);
+        let rotor = plane.exp();
+
+        assert_close(Cl3::from(::Multivector::from(rotor)), Cl3::from(::Multivector::from(plane)).exp());
+        assert_close(Cl3::from(::Multivector::from(rotor)), Cl3::from(::Multivector::from(plane)).exp_series());
+    }
+
+    #[test]
+    fn test_exp_of_small_angle_stays_unit() {
+        let b = Multivector::<3, 0, 0, f32>::blade(0b011, 0.01);
+        let rotor = b.exp();
+
+        assert_eq!(0.01f32.cos(), rotor.get(0));
+        assert_eq!(0.01f32.sin(), rotor.get(0b011));
+    }
+
+    #[test]
+    fn test_exp_other_signatures() {
+        let boost = Sta::blade(0b0011, 0.7);
+        let translation = Pga3::blade(0b1001, 0.5);
+
+        assert_close(Sta::scalar(0.7f64.cosh()) + Sta::blade(0b0011, 0.7f64.sinh()), boost.exp());
+        assert_close(boost.exp_series(), boost.exp());
+        assert_eq!(Pga3::scalar(1.0) + translation.clone(), translation.exp());
+    }
+
+    #[test]
+    fn test_exp_non_simple_bivector() {
+        let b = Cl4::blade(0b0011, 0.4) + Cl4::blade(0b1100, 1.1);
+        let expected = Cl4::blade(0b0011, 0.4).exp().geop(&Cl4::blade(0b1100, 1.1).exp());
+
+        assert!((expected - b.exp()).coeffs().iter().all(|c| c.abs() < 1e-12));
+    }
+
+    #[test]
+    fn test_grade_projection() {
+        let m = Cl3::from(::Multivector::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0));
+
+        assert_eq!(Cl3::from_vector(&[2.0, 3.0, 4.0]), m.grade(1));
+        assert_eq!(vec![0, 1, 2, 3], m.grades(0.0));
+        assert_eq!(vec![2], Cl4::blade(0b0011, 1.0).grades(0.0));
+    }
+
+    #[test]
+    fn test_is_blade() {
+        let e = |i| Cl4::basis_vector(i);
+
+        assert!((e(0) + e(1)).wedgep(&e(2)).is_blade(0.0));
+        assert!(!(e(0).wedgep(&e(1)) + e(2).wedgep(&e(3))).is_blade(0.0));
+        assert!(!(e(0) + e(0).wedgep(&e(1))).is_blade(0.0));
+        assert!(Pga3::basis_vector(3).wedgep(&Pga3::basis_vector(0)).is_blade(0.0));
+    }
+
+    #[test]
+    fn test_is_versor() {
+        let a = Cl4::from_vector(&[1.0, 2.0, 0.0, 1.0]);
+        let b = Cl4::from_vector(&[0.0, 1.0, 3.0, -1.0]);
+        let e0 = Pga3::basis_vector(3);
+
+        assert!(a.geop(&b).is_versor(1e-12));
+        assert!(a.geop(&b).geop(&a).is_versor(1e-12));
+        assert!(!(Cl4::scalar(1.0) + a.clone()).is_versor(1e-12));
+        assert!(!(Cl4::basis_vector(0).wedgep(&Cl4::basis_vector(1)) + Cl4::basis_vector(2).wedgep(&Cl4::basis_vector(3))).is_versor(1e-12));
+        assert!(!e0.is_versor(0.0));
+        assert!((Pga3::scalar(1.0) + e0.geop(&Pga3::basis_vector(0))).is_versor(0.0));
+    }
+
+    #[test]
+    fn test_metric_magnitude() {
+        let v = Sta::from_vector(&[2.0, 1.0, 0.0, 0.0]);
+        let null = Sta::from_vector(&[1.0, 1.0, 0.0, 0.0]);
+
+        assert_eq!(3.0f64.sqrt(), v.mag());
+        assert_eq!(0.0, null.mag());
+    }
+}
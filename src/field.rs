@@ -0,0 +1,211 @@
+//! Scalar types the algebras are generic over.
+//!
+//! `Ring` is all the products need, so integer and exact rational
+//! coefficients work for them. Anything needing square roots or
+//! trigonometry, such as magnitudes, angles and rotors, additionally
+//! requires `RealField`.
+
+use std::fmt::Debug;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+pub trait Ring: Copy + PartialEq + Debug
+    + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Neg<Output = Self>
+{
+    fn zero() -> Self;
+
+    fn one() -> Self;
+
+    fn is_zero(&self) -> bool {
+        *self == Self::zero()
+    }
+
+    /// Multiplies by a metric or reordering sign of -1, 0 or 1.
+    fn signed(self, sign: f64) -> Self {
+        if sign > 0.0 {
+            self
+        } else if sign < 0.0 {
+            -self
+        } else {
+            Self::zero()
+        }
+    }
+}
+
+pub trait RealField: Ring + PartialOrd + Div<Output = Self> {
+    fn from_f64(value: f64) -> Self;
+
+    fn to_f64(self) -> f64;
+
+    fn epsilon() -> Self;
+
+    fn pi() -> Self;
+
+    fn abs(self) -> Self;
+
+    fn sqrt(self) -> Self;
+
+    fn sin(self) -> Self;
+
+    fn cos(self) -> Self;
+
+    fn acos(self) -> Self;
+
+    fn atan2(self, other: Self) -> Self;
+
+    fn sinh(self) -> Self;
+
+    fn cosh(self) -> Self;
+
+    fn acosh(self) -> Self;
+
+    fn atanh(self) -> Self;
+
+    fn exp(self) -> Self;
+
+    fn ln(self) -> Self;
+
+    fn sin_cos(self) -> (Self, Self) {
+        (self.sin(), self.cos())
+    }
+}
+
+macro_rules! impl_ring_int {
+    ($($t:ty),*) => {$(
+        impl Ring for $t {
+            fn zero() -> Self { 0 }
+
+            fn one() -> Self { 1 }
+        }
+    )*}
+}
+
+impl_ring_int!(i8, i16, i32, i64, i128, isize);
+
+macro_rules! impl_real_field {
+    ($($t:ident),*) => {$(
+        impl Ring for $t {
+            fn zero() -> Self { 0.0 }
+
+            fn one() -> Self { 1.0 }
+        }
+
+        impl RealField for $t {
+            fn from_f64(value: f64) -> Self { value as $t }
+
+            fn to_f64(self) -> f64 { self as f64 }
+
+            fn epsilon() -> Self { $t::EPSILON }
+
+            fn pi() -> Self { ::std::$t::consts::PI }
+
+            fn abs(self) -> Self { $t::abs(self) }
+
+            fn sqrt(self) -> Self { $t::sqrt(self) }
+
+            fn sin(self) -> Self { $t::sin(self) }
+
+            fn cos(self) -> Self { $t::cos(self) }
+
+            fn acos(self) -> Self { $t::acos(self) }
+
+            fn atan2(self, other: Self) -> Self { $t::atan2(self, other) }
+
+            fn sinh(self) -> Self { $t::sinh(self) }
+
+            fn cosh(self) -> Self { $t::cosh(self) }
+
+            fn acosh(self) -> Self { $t::acosh(self) }
+
+            fn atanh(self) -> Self { $t::atanh(self) }
+
+            fn exp(self) -> Self { $t::exp(self) }
+
+            fn ln(self) -> Self { $t::ln(self) }
+
+            fn sin_cos(self) -> (Self, Self) { $t::sin_cos(self) }
+        }
+    )*}
+}
+
+impl_real_field!(f32, f64);
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use clifford::Multivector;
+    use {GeometricProduct, Magnitude, Rotor, Vector, WedgeProduct};
+
+    /// A dual number a + b ε with ε² = 0, for forward-mode derivatives.
+    #[derive(Clone, Copy, PartialEq, Debug)]
+    struct Dual(f64, f64);
+
+    impl Add for Dual {
+        type Output = Dual;
+
+        fn add(self, o: Dual) -> Dual { Dual(self.0 + o.0, self.1 + o.1) }
+    }
+
+    impl Sub for Dual {
+        type Output = Dual;
+
+        fn sub(self, o: Dual) -> Dual { Dual(self.0 - o.0, self.1 - o.1) }
+    }
+
+    impl Mul for Dual {
+        type Output = Dual;
+
+        fn mul(self, o: Dual) -> Dual { Dual(self.0 * o.0, self.0 * o.1 + self.1 * o.0) }
+    }
+
+    impl Neg for Dual {
+        type Output = Dual;
+
+        fn neg(self) -> Dual { Dual(-self.0, -self.1) }
+    }
+
+    impl Ring for Dual {
+        fn zero() -> Self { Dual(0.0, 0.0) }
+
+        fn one() -> Self { Dual(1.0, 0.0) }
+    }
+
+    #[test]
+    fn test_signed() {
+        assert_eq!(-3, 3.signed(-1.0));
+        assert_eq!(0, 3.signed(0.0));
+        assert_eq!(2.5, 2.5.signed(1.0));
+    }
+
+    #[test]
+    fn test_integer_products() {
+        let vec1 = Vector::new(1i64, 2, 3);
+        let vec2 = Vector::new(4i64, 5, 6);
+
+        assert_eq!(::Bivector::new(-3, -3, 6), vec1.wedgep(&vec2));
+        assert_eq!(32, vec1.geop(&vec2).scalar);
+
+        let e1 = Multivector::<2, 0, 0, i32>::basis_vector(0);
+        let e2 = Multivector::<2, 0, 0, i32>::basis_vector(1);
+        assert_eq!(Multivector::scalar(-1), e1.geop(&e2).geop(&e1.geop(&e2)));
+    }
+
+    #[test]
+    fn test_f32_rotor() {
+        let rotor = Rotor::from_axis_angle(&Vector::new(0.0f32, 0.0, 1.0), ::std::f32::consts::PI / 2.0);
+        let v = rotor.rotate(&Vector::new(1.0f32, 0.0, 0.0));
+
+        assert!((v.y - 1.0).abs() < 1e-6);
+        assert!((1.0f32 - v.mag()).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_dual_number_derivative() {
+        // The area e12 of (t, 1, 0) ∧ (1, t², 0) is t³ - 1, with derivative 3t².
+        let t = Dual(2.0, 1.0);
+        let vec1 = Vector::new(t, Dual::one(), Dual::zero());
+        let vec2 = Vector::new(Dual::one(), t * t, Dual::zero());
+
+        assert_eq!(Dual(7.0, 12.0), vec1.wedgep(&vec2).e12);
+    }
+}
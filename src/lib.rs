@@ -1,130 +1,1095 @@
+#[cfg(test)]
+mod approx;
+pub mod cga;
+mod cl2;
+pub mod clifford;
+pub mod field;
+mod ops;
+pub mod pga;
+mod rotor;
+pub mod sparse;
+pub mod sta;
+mod vectorn;
+
+pub use cl2::{Bivector2, Even2, Rotor2, Vector2};
+pub use field::{RealField, Ring};
+pub use rotor::{EulerSequence, Rotor};
+pub use vectorn::{BladeN, VectorN};
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Vector<T = f64> {
+    pub x: T,
+    pub y: T,
+    pub z: T
+}
+
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct Scalar<T = f64> {
+    pub value: T
+}
+
+/// An oriented plane element stored by its e12, e23 and e31 coefficients.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct Bivector<T = f64> {
+    pub e12: T,
+    pub e23: T,
+    pub e31: T
+}
+
+/// A bivector kept as the pair of vectors whose wedge spans it.
+#[derive(PartialEq, Debug)]
+pub struct FactoredBivector<'a, T: 'a = f64> {
+    pub x: &'a Vector<T>,
+    pub y: &'a Vector<T>
+}
+
+/// An oriented volume element, the multiple of the pseudoscalar e123.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct Trivector<T = f64> {
+    pub e123: T
+}
+
+/// Handedness of the frame spanned by a trivector.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Orientation {
+    RightHanded,
+    LeftHanded,
+    Degenerate
+}
+
+/// A general element of Cl(3,0): one scalar, three vector, three bivector
+/// and one trivector coefficient.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct Multivector<T = f64> {
+    pub scalar: T,
+    pub e1: T,
+    pub e2: T,
+    pub e3: T,
+    pub e12: T,
+    pub e23: T,
+    pub e31: T,
+    pub e123: T
+}
+
+impl<T: Ring> Vector<T> {
+    pub fn new(x: T, y: T, z: T) -> Self {
+        Vector {
+            x,
+            y,
+            z
+        }
+    }
+}
+
+impl<T: Ring> Multivector<T> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(scalar: T, e1: T, e2: T, e3: T, e12: T, e23: T, e31: T, e123: T) -> Self {
+        Multivector {
+            scalar,
+            e1,
+            e2,
+            e3,
+            e12,
+            e23,
+            e31,
+            e123
+        }
+    }
+
+    pub fn zero() -> Self {
+        let zero = T::zero();
+        Multivector::new(zero, zero, zero, zero, zero, zero, zero, zero)
+    }
+
+    pub fn vector(&self) -> Vector<T> {
+        Vector::new(self.e1, self.e2, self.e3)
+    }
+
+    pub fn bivector(&self) -> Bivector<T> {
+        Bivector::new(self.e12, self.e23, self.e31)
+    }
+
+    pub fn trivector(&self) -> Trivector<T> {
+        Trivector::new(self.e123)
+    }
+}
+
+impl<T: RealField> Multivector<T> {
+    /// Right division A B⁻¹, the X solving X B = A.
+    pub fn div_right(&self, b: &Multivector<T>) -> Option<Multivector<T>> {
+        b.inverse().map(|inv| self.geop(&inv))
+    }
+
+    /// Left division B⁻¹ A, the X solving B X = A.
+    pub fn div_left(&self, b: &Multivector<T>) -> Option<Multivector<T>> {
+        b.inverse().map(|inv| inv.geop(self))
+    }
+
+    /// The meet and join of two blades, together with the dimension of the
+    /// join. When the dimension falls short of the sum of the grades the
+    /// blades share a subspace and A ∧ B vanishes, so the join is instead
+    /// built up from the vector factors of both blades, and it is then a
+    /// unit blade oriented like A. The meet is (B ⌋ J⁻¹) ⌋ A. Coefficients
+    /// no larger than `tolerance` count as zero.
+    pub fn meet_join(&self, other: &Multivector<T>, tolerance: T) -> (Multivector<T>, Multivector<T>, usize) {
+        let mut join = Multivector::from(Scalar { value: T::one() });
+        let mut dimension = 0;
+        for v in self.blade_factors(tolerance).iter().chain(other.blade_factors(tolerance).iter()) {
+            let wider = join.wedgep(&Multivector::from(*v / v.mag()));
+            if wider.grades(tolerance).contains(&(dimension + 1)) {
+                join = wider / wider.mag();
+                dimension += 1;
+            }
+        }
+
+        let blade_grade = |m: &Multivector<T>| m.grades(tolerance).last().cloned().unwrap_or(0);
+        if dimension == blade_grade(self) + blade_grade(other) {
+            join = self.wedgep(other);
+        }
+        let meet = match join.inverse() {
+            Some(inv) => other.left_contraction(&inv).left_contraction(self),
+            None => Multivector::zero()
+        };
+        (meet, join, dimension)
+    }
+
+    /// Unit vectors n₁, …, nₖ, the first scaled to absorb the magnitude and
+    /// sign of the versor, with n₁ n₂ ⋯ nₖ equal to this versor, or `None`
+    /// if it is not one. The count is minimal, three minus the dimension of
+    /// the fixed subspace (Cartan–Dieudonné), except that a scalar versor
+    /// other than 1 needs the pair λe₁, e₁.
+    pub fn reflections(&self, tolerance: T) -> Option<Vec<Vector<T>>> {
+        if !self.is_versor(tolerance) {
+            return None;
+        }
+        let inv = self.inverse()?;
+        let odd = self.grades(tolerance)[0] % 2 == 1;
+        let image = |e: Vector<T>| {
+            let v = self.geop(&Multivector::from(e)).geop(&inv).vector();
+            if odd { -v } else { v }
+        };
+        let [e1, e2, e3] = basis();
+        let mut normals = reflection_normals([image(e1), image(e2), image(e3)], tolerance);
+
+        let scale = self.geop(&versor_product(&normals).inverse()?).scalar;
+        if normals.is_empty() {
+            if (scale - T::one()).abs() <= tolerance {
+                return Some(normals);
+            }
+            normals = vec![e1, e1];
+        }
+        normals[0] = normals[0] * scale;
+        Some(normals)
+    }
+
+    /// The unit versor of the orthogonal map y = M x, a rotor when det M = 1
+    /// and an odd versor when det M = -1, or `None` if M is not orthogonal
+    /// to within `tolerance`.
+    pub fn from_orthogonal_matrix(m: &[[T; 3]; 3], tolerance: T) -> Option<Multivector<T>> {
+        let columns = [
+            Vector::new(m[0][0], m[1][0], m[2][0]),
+            Vector::new(m[0][1], m[1][1], m[2][1]),
+            Vector::new(m[0][2], m[1][2], m[2][2])
+        ];
+        for i in 0..3 {
+            for j in 0..3 {
+                let expected = if i == j { T::one() } else { T::zero() };
+                if exceeds(columns[i].innerp(&columns[j]).value - expected, tolerance) {
+                    return None;
+                }
+            }
+        }
+        Some(versor_product(&reflection_normals(columns, tolerance)))
+    }
+
+    /// Spanning vectors of the highest grade part of a blade.
+    fn blade_factors(&self, tolerance: T) -> Vec<Vector<T>> {
+        match self.grades(tolerance).last() {
+            Some(&1) => vec![self.vector()],
+            Some(&2) => self.bivector().factor().map_or(Vec::new(), |(a, b)| vec![a, b]),
+            Some(&3) => self.trivector().factor().map_or(Vec::new(), |(a, b, c)| vec![a, b, c]),
+            _ => Vec::new()
+        }
+    }
+}
+
+fn basis<T: Ring>() -> [Vector<T>; 3] {
+    let (zero, one) = (T::zero(), T::one());
+    [Vector::new(one, zero, zero), Vector::new(zero, one, zero), Vector::new(zero, zero, one)]
+}
+
+/// Unit normals n₁, …, nₖ of hyperplane reflections whose composition is
+/// the orthogonal map taking each basis vector to its entry in `images`.
+/// Each step reflects the remaining image of eᵢ back onto eᵢ, which leaves
+/// the basis vectors already fixed untouched.
+fn reflection_normals<T: RealField>(mut images: [Vector<T>; 3], tolerance: T) -> Vec<Vector<T>> {
+    let mut normals = Vec::new();
+    for (i, e) in basis().iter().enumerate() {
+        let n = images[i] - *e;
+        if n.mag() > tolerance {
+            let n = n / n.mag();
+            for image in images.iter_mut() {
+                *image = -image.reflect_in(&n);
+            }
+            normals.push(n);
+        }
+    }
+    normals
+}
+
+fn versor_product<T: Ring>(vectors: &[Vector<T>]) -> Multivector<T> {
+    vectors.iter().fold(Multivector::from(Scalar { value: T::one() }), |acc, v| acc.geop(&Multivector::from(v)))
+}
+
+impl<T: Ring> Bivector<T> {
+    pub fn new(e12: T, e23: T, e31: T) -> Self {
+        Bivector {
+            e12,
+            e23,
+            e31
+        }
+    }
+
+    pub fn from_vectors(x: &Vector<T>, y: &Vector<T>) -> Self {
+        x.wedgep(y)
+    }
+}
+
+impl<T: Ring> Trivector<T> {
+    pub fn new(e123: T) -> Self {
+        Trivector {
+            e123
+        }
+    }
+
+    pub fn from_vectors(x: &Vector<T>, y: &Vector<T>, z: &Vector<T>) -> Self {
+        x.wedgep(y).wedgep(z)
+    }
+
+    /// The unit pseudoscalar I = e123.
+    pub fn pseudoscalar() -> Self {
+        Trivector::new(T::one())
+    }
+}
+
+impl<T: Ring + PartialOrd> Trivector<T> {
+    pub fn orientation(&self) -> Orientation {
+        if self.e123 > T::zero() {
+            Orientation::RightHanded
+        } else if self.e123 < T::zero() {
+            Orientation::LeftHanded
+        } else {
+            Orientation::Degenerate
+        }
+    }
+}
+
+impl<T: RealField> Bivector<T> {
+    /// Orthogonal vectors (a, b) with a ∧ b = B, or `None` for the zero
+    /// bivector. The first factor is a unit vector and the second carries
+    /// the area |B|, turned a quarter turn from the first in the sense of B.
+    pub fn factor(&self) -> Option<(Vector<T>, Vector<T>)> {
+        let plane = Multivector::from(*self);
+        let mut first = Vector::new(T::zero(), T::zero(), T::zero());
+        for e in &basis() {
+            let candidate = Multivector::from(e).left_contraction(&plane).vector();
+            if candidate.mag() > first.mag() {
+                first = candidate;
+            }
+        }
+        let mag = first.mag();
+        if mag.is_zero() {
+            return None;
+        }
+        let second = Multivector::from(first).left_contraction(&plane).vector();
+        Some((first / mag, second / mag))
+    }
+}
+
+impl<T: RealField> Trivector<T> {
+    /// Orthogonal vectors (a, b, c) with a ∧ b ∧ c = T, or `None` for the
+    /// zero trivector. The first two are e1 and e2 and the third carries the
+    /// signed volume along e3.
+    pub fn factor(&self) -> Option<(Vector<T>, Vector<T>, Vector<T>)> {
+        if self.e123.is_zero() {
+            return None;
+        }
+        let [e1, e2, e3] = basis();
+        Some((e1, e2, e3 * self.e123))
+    }
+}
+
+impl<'a, T: Ring> FactoredBivector<'a, T> {
+    pub fn from_vectors(x: &'a Vector<T>, y: &'a Vector<T>) -> Self {
+        FactoredBivector {
+            x,
+            y
+        }
+    }
+
+    pub fn to_bivector(&self) -> Bivector<T> {
+        self.x.wedgep(self.y)
+    }
+}
+
+pub trait Magnitude<T = f64> {
+    fn mag(&self) -> T;
+}
+
+pub trait Angle<Rhs = Self, T = f64> {
+    fn angle(&self, other: &Rhs) -> T;
+}
+
+pub trait InnerProduct<Rhs = Self> {
+    type Output;
+
+    fn innerp(&self, other: &Rhs) -> Self::Output;
+}
+
+/// The inner products generalising the vector dot product to all grades.
+/// Each keeps, for homogeneous parts Aᵢ and Bⱼ, at most the single grade
+/// part ⟨AᵢBⱼ⟩ₖ of their geometric product; they all agree with the dot
+/// product on vectors but differ in which grades survive and in how they
+/// treat scalars.
+pub trait InnerProducts<Rhs = Self> {
+    type Output;
+
+    /// Left contraction A ⌋ B, with k = j - i and zero when i > j. A scalar
+    /// on the left scales B, while a scalar on the right only survives
+    /// against the scalar part of A. Projections rely on this product.
+    fn left_contraction(&self, other: &Rhs) -> Self::Output;
+
+    /// Right contraction A ⌊ B, with k = i - j and zero when j > i. A scalar
+    /// on the right scales A, while a scalar on the left only survives
+    /// against the scalar part of B.
+    fn right_contraction(&self, other: &Rhs) -> Self::Output;
+
+    /// Scalar product A * B = ⟨AB⟩₀, pairing equal grades only. Scalars
+    /// multiply as numbers.
+    fn scalar_product(&self, other: &Rhs) -> Self::Output;
+
+    /// Hestenes inner product, with k = |i - j| and zero whenever either
+    /// side is a scalar.
+    fn hestenes(&self, other: &Rhs) -> Self::Output;
+
+    /// Fat dot product, with k = |i - j| and scalars on either side scaling
+    /// the other argument.
+    fn fat_dot(&self, other: &Rhs) -> Self::Output;
+}
+
+/// The cross product, which in three dimensions is the dual of the wedge.
+pub trait OuterProduct<Rhs = Self> {
+    type Output;
+
+    fn outerp(&self, other: &Rhs) -> Self::Output;
+}
+
+pub trait WedgeProduct<Rhs = Self> {
+    type Output;
+
+    fn wedgep(&self, other: &Rhs) -> Self::Output;
+}
+
+pub trait GeometricProduct<Rhs = Self> {
+    type Output;
+
+    fn geop(&self, other: &Rhs) -> Self::Output;
+}
+
+/// Intersection of the subspaces represented by two elements.
+pub trait Meet<Rhs = Self> {
+    type Output;
+
+    fn meet(&self, other: &Rhs) -> Self::Output;
+}
+
+/// The smallest subspace containing both elements.
+pub trait Join<Rhs = Self> {
+    type Output;
+
+    fn join(&self, other: &Rhs) -> Self::Output;
+}
+
+/// Duality through the unit pseudoscalar I, mapping each grade k to the
+/// complementary grade n - k.
+pub trait Dual {
+    type Output;
+
+    /// The dual A I⁻¹.
+    fn dual(&self) -> Self::Output;
+
+    /// The inverse of `dual`, A I.
+    fn undual(&self) -> Self::Output;
+
+    /// The Hodge star ⋆A = Ã I, for which A ∧ ⋆A = ⟨ÃA⟩₀ I.
+    fn hodge(&self) -> Self::Output;
+}
+
+/// The multiplicative inverse A⁻¹ with A A⁻¹ = A⁻¹ A = 1, or `None` when
+/// the element is zero or otherwise not invertible.
+pub trait Inverse: Sized {
+    fn inverse(&self) -> Option<Self>;
+}
+
+/// Orthogonal decomposition of an element relative to the subspace of a
+/// blade A, through the geometric product: the projection (x ⌋ A) A⁻¹ and
+/// the rejection (x ∧ A) A⁻¹, which sum to x. A zero blade spans only the
+/// origin, so everything is rejected from it.
+pub trait Project<Blade> {
+    fn project_onto(&self, blade: &Blade) -> Self;
+
+    fn reject_from(&self, blade: &Blade) -> Self;
+}
+
+/// Reflection in the subspace of a blade, keeping the part of the element
+/// inside it and negating the part orthogonal to it.
+pub trait Reflect<Blade> {
+    fn reflect_in(&self, blade: &Blade) -> Self;
+}
+
+/// Reversion Ã, reversing the order of the vector factors of each blade.
+/// A grade-k part changes sign by (-1)^(k(k-1)/2), and (AB)~ = B̃Ã.
+pub trait Reverse {
+    fn reverse(&self) -> Self;
+}
+
+/// Grade involution Â, negating every vector factor. A grade-k part
+/// changes sign by (-1)^k, and (AB)^ = ÂB̂.
+pub trait GradeInvolution {
+    fn involute(&self) -> Self;
+}
+
+/// Clifford conjugation Ā, the composition of reversion and grade
+/// involution. A grade-k part changes sign by (-1)^(k(k+1)/2).
+pub trait Conjugate {
+    fn conjugate(&self) -> Self;
+}
+
+/// Grade projection ⟨A⟩ₖ and queries on the grades present in an element.
+/// Coefficients no larger than `tolerance` in magnitude count as zero.
+pub trait Grade<T = f64> {
+    fn grade(&self, k: usize) -> Self;
+
+    /// The grades with a non-negligible coefficient, in increasing order.
+    fn grades(&self, tolerance: T) -> Vec<usize>;
+
+    /// Whether at most one grade is present.
+    fn is_homogeneous(&self, tolerance: T) -> bool {
+        self.grades(tolerance).len() <= 1
+    }
+
+    /// Whether the element is an outer product of vectors.
+    fn is_blade(&self, tolerance: T) -> bool;
+
+    /// Whether the element is an invertible geometric product of vectors.
+    fn is_versor(&self, tolerance: T) -> bool;
+}
+
+/// Whether `c` exceeds `tolerance` in magnitude.
+fn exceeds<T: Ring + PartialOrd>(c: T, tolerance: T) -> bool {
+    c > tolerance || -c > tolerance
+}
+
+impl<T: RealField> Magnitude<T> for Vector<T> {
+    fn mag(&self) -> T {
+        (self.x * self.x + self.y * self.y + self.z * self.z).sqrt()
+    }
+}
+
+impl<T: RealField> Magnitude<T> for Bivector<T> {
+    fn mag(&self) -> T {
+        (self.e12 * self.e12 + self.e23 * self.e23 + self.e31 * self.e31).sqrt()
+    }
+}
+
+/// Signed volume of the parallelepiped spanned by the trivector's factors.
+impl<T: RealField> Magnitude<T> for Trivector<T> {
+    fn mag(&self) -> T {
+        self.e123
+    }
+}
+
+impl<'a, T: RealField> Magnitude<T> for FactoredBivector<'a, T> {
+    fn mag(&self) -> T {
+        self.x.mag() * self.y.mag() * self.x.angle(self.y).sin()
+    }
+}
+
+impl<T: RealField> Magnitude<T> for Multivector<T> {
+    fn mag(&self) -> T {
+        self.geop(&self.reverse()).scalar.sqrt()
+    }
+}
+
+impl<T: RealField> Angle<Vector<T>, T> for Vector<T> {
+    fn angle(&self, other: &Vector<T>) -> T {
+        (self.innerp(other).value / (self.mag() * other.mag())).acos()
+    }
+}
+
+impl<T: Ring> InnerProduct for Vector<T> {
+    type Output = Scalar<T>;
+
+    fn innerp(&self, other: &Vector<T>) -> Scalar<T> {
+        Scalar {
+            value: self.x * other.x + self.y * other.y + self.z * other.z
+        }
+    }
+}
+
+impl<T: Ring> OuterProduct for Vector<T> {
+    type Output = Vector<T>;
+
+    /// The cross product, the dual (a ∧ b) I⁻¹ of the wedge.
+    fn outerp(&self, other: &Vector<T>) -> Vector<T> {
+        self.wedgep(other).dual()
+    }
+}
+
+impl<T: Ring> WedgeProduct for Vector<T> {
+    type Output = Bivector<T>;
+
+    fn wedgep(&self, other: &Vector<T>) -> Bivector<T> {
+        Bivector {
+            e12: self.x * other.y - self.y * other.x,
+            e23: self.y * other.z - self.z * other.y,
+            e31: self.z * other.x - self.x * other.z
+        }
+    }
+}
+
+impl<T: Ring> WedgeProduct<Vector<T>> for Bivector<T> {
+    type Output = Trivector<T>;
+
+    fn wedgep(&self, other: &Vector<T>) -> Trivector<T> {
+        Trivector {
+            e123: self.e12 * other.z + self.e23 * other.x + self.e31 * other.y
+        }
+    }
+}
+
+impl<T: Ring> WedgeProduct<Bivector<T>> for Vector<T> {
+    type Output = Trivector<T>;
+
+    fn wedgep(&self, other: &Bivector<T>) -> Trivector<T> {
+        other.wedgep(self)
+    }
+}
+
+impl<T: Ring> GeometricProduct for Vector<T> {
+    type Output = Multivector<T>;
+
+    fn geop(&self, other: &Vector<T>) -> Multivector<T> {
+        Multivector::from(self.innerp(other)) + Multivector::from(self.wedgep(other))
+    }
+}
+
+impl<T: Ring> GeometricProduct for Multivector<T> {
+    type Output = Multivector<T>;
+
+    fn geop(&self, b: &Multivector<T>) -> Multivector<T> {
+        let a = self;
+        Multivector {
+            scalar: a.scalar * b.scalar + a.e1 * b.e1 + a.e2 * b.e2 + a.e3 * b.e3
+                - a.e12 * b.e12 - a.e23 * b.e23 - a.e31 * b.e31 - a.e123 * b.e123,
+            e1: a.scalar * b.e1 + a.e1 * b.scalar - a.e2 * b.e12 + a.e3 * b.e31
+                + a.e12 * b.e2 - a.e23 * b.e123 - a.e31 * b.e3 - a.e123 * b.e23,
+            e2: a.scalar * b.e2 + a.e1 * b.e12 + a.e2 * b.scalar - a.e3 * b.e23
+                - a.e12 * b.e1 + a.e23 * b.e3 - a.e31 * b.e123 - a.e123 * b.e31,
+            e3: a.scalar * b.e3 - a.e1 * b.e31 + a.e2 * b.e23 + a.e3 * b.scalar
+                - a.e12 * b.e123 - a.e23 * b.e2 + a.e31 * b.e1 - a.e123 * b.e12,
+            e12: a.scalar * b.e12 + a.e1 * b.e2 - a.e2 * b.e1 + a.e3 * b.e123
+                + a.e12 * b.scalar - a.e23 * b.e31 + a.e31 * b.e23 + a.e123 * b.e3,
+            e23: a.scalar * b.e23 + a.e1 * b.e123 + a.e2 * b.e3 - a.e3 * b.e2
+                + a.e12 * b.e31 + a.e23 * b.scalar - a.e31 * b.e12 + a.e123 * b.e1,
+            e31: a.scalar * b.e31 - a.e1 * b.e3 + a.e2 * b.e123 + a.e3 * b.e1
+                - a.e12 * b.e23 + a.e23 * b.e12 + a.e31 * b.scalar + a.e123 * b.e2,
+            e123: a.scalar * b.e123 + a.e1 * b.e23 + a.e2 * b.e31 + a.e3 * b.e12
+                + a.e12 * b.e3 + a.e23 * b.e1 + a.e31 * b.e2 + a.e123 * b.scalar
+        }
+    }
+}
+
+impl<T: Ring> WedgeProduct for Multivector<T> {
+    type Output = Multivector<T>;
+
+    fn wedgep(&self, b: &Multivector<T>) -> Multivector<T> {
+        let a = self;
+        Multivector {
+            scalar: a.scalar * b.scalar,
+            e1: a.scalar * b.e1 + a.e1 * b.scalar,
+            e2: a.scalar * b.e2 + a.e2 * b.scalar,
+            e3: a.scalar * b.e3 + a.e3 * b.scalar,
+            e12: a.scalar * b.e12 + a.e1 * b.e2 - a.e2 * b.e1 + a.e12 * b.scalar,
+            e23: a.scalar * b.e23 + a.e2 * b.e3 - a.e3 * b.e2 + a.e23 * b.scalar,
+            e31: a.scalar * b.e31 - a.e1 * b.e3 + a.e3 * b.e1 + a.e31 * b.scalar,
+            e123: a.scalar * b.e123 + a.e1 * b.e23 + a.e2 * b.e31 + a.e3 * b.e12
+                + a.e12 * b.e3 + a.e23 * b.e1 + a.e31 * b.e2 + a.e123 * b.scalar
+        }
+    }
+}
+
+/// The left contraction, see `InnerProducts::left_contraction`.
+impl<T: Ring> InnerProduct for Multivector<T> {
+    type Output = Multivector<T>;
+
+    fn innerp(&self, b: &Multivector<T>) -> Multivector<T> {
+        self.left_contraction(b)
+    }
+}
+
+impl<T: Ring> InnerProducts for Multivector<T> {
+    type Output = Multivector<T>;
+
+    fn left_contraction(&self, b: &Multivector<T>) -> Multivector<T> {
+        let a = self;
+        Multivector {
+            scalar: a.scalar_product(b).scalar,
+            e1: a.scalar * b.e1 - a.e2 * b.e12 + a.e3 * b.e31 - a.e23 * b.e123,
+            e2: a.scalar * b.e2 + a.e1 * b.e12 - a.e3 * b.e23 - a.e31 * b.e123,
+            e3: a.scalar * b.e3 - a.e1 * b.e31 + a.e2 * b.e23 - a.e12 * b.e123,
+            e12: a.scalar * b.e12 + a.e3 * b.e123,
+            e23: a.scalar * b.e23 + a.e1 * b.e123,
+            e31: a.scalar * b.e31 + a.e2 * b.e123,
+            e123: a.scalar * b.e123
+        }
+    }
+
+    fn right_contraction(&self, b: &Multivector<T>) -> Multivector<T> {
+        let a = self;
+        Multivector {
+            scalar: a.scalar_product(b).scalar,
+            e1: a.e1 * b.scalar + a.e12 * b.e2 - a.e31 * b.e3 - a.e123 * b.e23,
+            e2: a.e2 * b.scalar - a.e12 * b.e1 + a.e23 * b.e3 - a.e123 * b.e31,
+            e3: a.e3 * b.scalar - a.e23 * b.e2 + a.e31 * b.e1 - a.e123 * b.e12,
+            e12: a.e12 * b.scalar + a.e123 * b.e3,
+            e23: a.e23 * b.scalar + a.e123 * b.e1,
+            e31: a.e31 * b.scalar + a.e123 * b.e2,
+            e123: a.e123 * b.scalar
+        }
+    }
+
+    fn scalar_product(&self, b: &Multivector<T>) -> Multivector<T> {
+        let a = self;
+        Multivector {
+            scalar: a.scalar * b.scalar + a.e1 * b.e1 + a.e2 * b.e2 + a.e3 * b.e3
+                - a.e12 * b.e12 - a.e23 * b.e23 - a.e31 * b.e31 - a.e123 * b.e123,
+            ..Multivector::zero()
+        }
+    }
+
+    /// The fat dot product without the terms involving a scalar part.
+    fn hestenes(&self, b: &Multivector<T>) -> Multivector<T> {
+        let (a0, b0) = (self.scalar, b.scalar);
+        let scalars = Multivector { scalar: a0 * b0, ..Multivector::zero() };
+        self.fat_dot(b) - *b * a0 - *self * b0 + scalars
+    }
+
+    /// Both contractions together, with the equal-grade terms they share
+    /// counted once.
+    fn fat_dot(&self, b: &Multivector<T>) -> Multivector<T> {
+        self.left_contraction(b) + self.right_contraction(b) - self.scalar_product(b)
+    }
+}
+
+/// The tolerance `Meet` and `Join` pass to `meet_join`, √ε scaled by the
+/// magnitudes of both blades so that small blades are not taken for zero.
+fn meet_join_tolerance<T: RealField>(a: &Multivector<T>, b: &Multivector<T>) -> T {
+    T::epsilon().sqrt() * a.mag() * b.mag()
+}
+
+/// The intersection of two blades, see `Multivector::meet_join`.
+impl<T: RealField> Meet for Multivector<T> {
+    type Output = Multivector<T>;
+
+    fn meet(&self, other: &Multivector<T>) -> Multivector<T> {
+        self.meet_join(other, meet_join_tolerance(self, other)).0
+    }
+}
+
+/// The span of two blades, see `Multivector::meet_join`.
+impl<T: RealField> Join for Multivector<T> {
+    type Output = Multivector<T>;
+
+    fn join(&self, other: &Multivector<T>) -> Multivector<T> {
+        self.meet_join(other, meet_join_tolerance(self, other)).1
+    }
+}
+
+/// The line shared by two planes through the origin, or the plane itself
+/// when they coincide.
+impl<T: RealField> Meet for Bivector<T> {
+    type Output = Multivector<T>;
+
+    fn meet(&self, other: &Bivector<T>) -> Multivector<T> {
+        Multivector::from(*self).meet(&Multivector::from(*other))
+    }
+}
+
+impl<T: RealField> Join for Bivector<T> {
+    type Output = Multivector<T>;
+
+    fn join(&self, other: &Bivector<T>) -> Multivector<T> {
+        Multivector::from(*self).join(&Multivector::from(*other))
+    }
+}
+
+impl<T: Ring> From<Scalar<T>> for Multivector<T> {
+    fn from(s: Scalar<T>) -> Self {
+        Multivector { scalar: s.value, ..Multivector::zero() }
+    }
+}
+
+impl<'a, T: Ring> From<&'a Vector<T>> for Multivector<T> {
+    fn from(v: &'a Vector<T>) -> Self {
+        Multivector { e1: v.x, e2: v.y, e3: v.z, ..Multivector::zero() }
+    }
+}
+
+impl<T: Ring> From<Vector<T>> for Multivector<T> {
+    fn from(v: Vector<T>) -> Self {
+        Multivector::from(&v)
+    }
+}
+
+impl<T: Ring> From<Bivector<T>> for Multivector<T> {
+    fn from(b: Bivector<T>) -> Self {
+        Multivector { e12: b.e12, e23: b.e23, e31: b.e31, ..Multivector::zero() }
+    }
+}
+
+impl<'a, T: Ring> From<FactoredBivector<'a, T>> for Multivector<T> {
+    fn from(b: FactoredBivector<'a, T>) -> Self {
+        Multivector::from(b.to_bivector())
+    }
+}
+
+impl<T: Ring> From<Trivector<T>> for Multivector<T> {
+    fn from(t: Trivector<T>) -> Self {
+        Multivector { e123: t.e123, ..Multivector::zero() }
+    }
+}
+
+/// In Cl(3,0) I² = -1, so I⁻¹ = -I. The Hodge star agrees with the undual
+/// on scalars and vectors and with the dual on bivectors and trivectors.
+impl<T: Ring> Dual for Scalar<T> {
+    type Output = Trivector<T>;
+
+    fn dual(&self) -> Trivector<T> {
+        Trivector::new(-self.value)
+    }
+
+    fn undual(&self) -> Trivector<T> {
+        Trivector::new(self.value)
+    }
+
+    fn hodge(&self) -> Trivector<T> {
+        self.undual()
+    }
+}
+
+impl<T: Ring> Dual for Vector<T> {
+    type Output = Bivector<T>;
+
+    fn dual(&self) -> Bivector<T> {
+        Bivector::new(-self.z, -self.x, -self.y)
+    }
+
+    fn undual(&self) -> Bivector<T> {
+        Bivector::new(self.z, self.x, self.y)
+    }
+
+    fn hodge(&self) -> Bivector<T> {
+        self.undual()
+    }
+}
+
+impl<T: Ring> Dual for Bivector<T> {
+    type Output = Vector<T>;
+
+    fn dual(&self) -> Vector<T> {
+        Vector::new(self.e23, self.e31, self.e12)
+    }
+
+    fn undual(&self) -> Vector<T> {
+        Vector::new(-self.e23, -self.e31, -self.e12)
+    }
+
+    fn hodge(&self) -> Vector<T> {
+        self.dual()
+    }
+}
+
+impl<T: Ring> Dual for Trivector<T> {
+    type Output = Scalar<T>;
+
+    fn dual(&self) -> Scalar<T> {
+        Scalar { value: self.e123 }
+    }
 
-#[derive(PartialEq, Debug)]
-struct Vector {
-    x: f64,
-    y: f64,
-    z: f64
+    fn undual(&self) -> Scalar<T> {
+        Scalar { value: -self.e123 }
+    }
+
+    fn hodge(&self) -> Scalar<T> {
+        self.dual()
+    }
+}
+
+impl<T: Ring> Dual for Multivector<T> {
+    type Output = Multivector<T>;
+
+    fn dual(&self) -> Multivector<T> {
+        self.geop(&Multivector::from(Trivector::new(-Trivector::<T>::pseudoscalar().e123)))
+    }
+
+    fn undual(&self) -> Multivector<T> {
+        self.geop(&Multivector::from(Trivector::<T>::pseudoscalar()))
+    }
+
+    fn hodge(&self) -> Multivector<T> {
+        self.reverse().undual()
+    }
 }
 
-struct Scalar {
-    value: f64
+impl<T: RealField> Inverse for Scalar<T> {
+    fn inverse(&self) -> Option<Self> {
+        if self.value.is_zero() {
+            return None;
+        }
+        Some(Scalar { value: T::one() / self.value })
+    }
 }
 
-#[derive(PartialEq, Debug)]
-struct Bivector<'a> {
-    x: &'a Vector,
-    y: &'a Vector
+/// v / |v|².
+impl<T: RealField> Inverse for Vector<T> {
+    fn inverse(&self) -> Option<Self> {
+        let sq = self.innerp(self).value;
+        if sq.is_zero() {
+            return None;
+        }
+        Some(*self / sq)
+    }
 }
 
-struct Trivector<'a> {
-    x: &'a Vector,
-    y: &'a Vector,
-    z: &'a Vector
+/// -B / |B|², since B² = -|B|².
+impl<T: RealField> Inverse for Bivector<T> {
+    fn inverse(&self) -> Option<Self> {
+        let sq = self.e12 * self.e12 + self.e23 * self.e23 + self.e31 * self.e31;
+        if sq.is_zero() {
+            return None;
+        }
+        Some(-*self / sq)
+    }
 }
 
-impl Vector {
-    pub fn new(x: f64, y: f64, z: f64) -> Self {
-        Vector {
-            x,
-            y,
-            z
+/// -T / t², since I² = -1.
+impl<T: RealField> Inverse for Trivector<T> {
+    fn inverse(&self) -> Option<Self> {
+        if self.e123.is_zero() {
+            return None;
         }
+        Some(Trivector::new(-T::one() / self.e123))
     }
 }
 
-impl<'a> Bivector<'a> {
-    pub fn from_vectors(x: &'a Vector, y: &'a Vector) -> Self {
-        Bivector {
-            x,
-            y
+/// In Cl(3,0) A Ā = α + βI lies in the centre of the algebra, and
+/// (α + βI)(α - βI) = α² + β², so A⁻¹ = Ā (α - βI) / (α² + β²). This covers
+/// blades and versors as well as mixed elements such as 1 + e12 + e123.
+impl<T: RealField> Inverse for Multivector<T> {
+    fn inverse(&self) -> Option<Self> {
+        let conj = self.conjugate();
+        let norm = self.geop(&conj);
+        let denom = norm.scalar * norm.scalar + norm.e123 * norm.e123;
+        if denom.is_zero() {
+            return None;
+        }
+        let central = Multivector { scalar: norm.scalar, e123: -norm.e123, ..Multivector::zero() };
+        Some(conj.geop(&central) / denom)
+    }
+}
+
+impl<T: RealField> Project<Vector<T>> for Vector<T> {
+    fn project_onto(&self, a: &Vector<T>) -> Self {
+        match a.inverse() {
+            Some(inv) => Multivector::from(self.innerp(a)).geop(&Multivector::from(inv)).vector(),
+            None => Vector::new(T::zero(), T::zero(), T::zero())
+        }
+    }
+
+    fn reject_from(&self, a: &Vector<T>) -> Self {
+        match a.inverse() {
+            Some(inv) => Multivector::from(self.wedgep(a)).geop(&Multivector::from(inv)).vector(),
+            None => *self
         }
     }
 }
 
-trait Magnitude {
-    fn mag(&self) -> f64;
+impl<T: RealField> Project<Bivector<T>> for Vector<T> {
+    fn project_onto(&self, b: &Bivector<T>) -> Self {
+        match b.inverse() {
+            Some(inv) => Multivector::from(self).left_contraction(&Multivector::from(*b)).geop(&Multivector::from(inv)).vector(),
+            None => Vector::new(T::zero(), T::zero(), T::zero())
+        }
+    }
+
+    fn reject_from(&self, b: &Bivector<T>) -> Self {
+        match b.inverse() {
+            Some(inv) => Multivector::from(self.wedgep(b)).geop(&Multivector::from(inv)).vector(),
+            None => *self
+        }
+    }
 }
 
-trait Angle {
-    fn angle(&self, other: &Vector) -> f64;
+/// Reflection in the line along `a`, a x a⁻¹.
+impl<T: RealField> Reflect<Vector<T>> for Vector<T> {
+    fn reflect_in(&self, a: &Vector<T>) -> Self {
+        match a.inverse() {
+            Some(inv) => a.geop(self).geop(&Multivector::from(inv)).vector(),
+            None => -*self
+        }
+    }
 }
 
-trait InnerProduct {
-    fn innerp(&self, other: &Vector) -> Scalar;
+/// Reflection in the plane of `b`, -B x B⁻¹.
+impl<T: RealField> Reflect<Bivector<T>> for Vector<T> {
+    fn reflect_in(&self, b: &Bivector<T>) -> Self {
+        match b.inverse() {
+            Some(inv) => -Multivector::from(*b).geop(&Multivector::from(self)).geop(&Multivector::from(inv)).vector(),
+            None => -*self
+        }
+    }
 }
 
-trait OuterProduct {
-    fn outerp(&self, other: &Vector) -> Vector;
+impl<T: Ring> Reverse for Scalar<T> {
+    fn reverse(&self) -> Self {
+        *self
+    }
 }
 
-trait WedgeProduct<'a> {
-    fn wedgep(&'a self, other: &'a Vector) -> Bivector;
+impl<T: Ring> GradeInvolution for Scalar<T> {
+    fn involute(&self) -> Self {
+        *self
+    }
 }
 
-trait GeometricProduct<'a> {
-    fn geop(&'a self, other: &'a Vector) -> (Scalar, Bivector);
+impl<T: Ring> Conjugate for Scalar<T> {
+    fn conjugate(&self) -> Self {
+        *self
+    }
 }
 
-impl Magnitude for Vector {
-    fn mag(&self) -> f64 {
-        (self.x.powi(2) + self.y.powi(2) + self.z.powi(2)).sqrt()
+impl<T: Ring> Reverse for Vector<T> {
+    fn reverse(&self) -> Self {
+        *self
     }
 }
 
-impl<'a> Magnitude for Bivector<'a> {
-    fn mag(&self) -> f64 {
-        self.x.mag() * self.y.mag() * self.x.angle(&self.y).sin()
+impl<T: Ring> GradeInvolution for Vector<T> {
+    fn involute(&self) -> Self {
+        -*self
     }
 }
 
-impl Angle for Vector {
-    fn angle(&self, other: &Vector) -> f64 {
-        (self.innerp(other).value / (self.mag() * other.mag())).acos()
+impl<T: Ring> Conjugate for Vector<T> {
+    fn conjugate(&self) -> Self {
+        -*self
     }
 }
 
-impl InnerProduct for Vector {
-    fn innerp(&self, other: &Vector) -> Scalar {
-        Scalar {
-            value: self.x * other.x + self.y * other.y + self.z * other.z
-        }
+impl<T: Ring> Reverse for Bivector<T> {
+    fn reverse(&self) -> Self {
+        -*self
     }
 }
 
-impl OuterProduct for Vector {
-    fn outerp(&self, other: &Vector) -> Vector {
-        Vector {
-            x: self.y * other.z - self.z * other.y,
-            y: self.z * other.x - self.x * other.z,
-            z: self.x * other.y - self.y * other.x
-        }
+impl<T: Ring> GradeInvolution for Bivector<T> {
+    fn involute(&self) -> Self {
+        *self
     }
 }
 
-impl<'a> WedgeProduct<'a> for Vector {
-    fn wedgep(&'a self, other: &'a Vector) -> Bivector {
-        Bivector {
-            x: self,
-            y: other
-        }
-    }    
+impl<T: Ring> Conjugate for Bivector<T> {
+    fn conjugate(&self) -> Self {
+        -*self
+    }
 }
 
-impl<'a> GeometricProduct<'a> for Vector {
-    fn geop(&'a self, other: &'a Vector) -> (Scalar, Bivector) {
-        (self.innerp(other),
-        Bivector {
-            x: self,
-            y: other
-        })
-    }    
+impl<T: Ring> Reverse for Trivector<T> {
+    fn reverse(&self) -> Self {
+        -*self
+    }
+}
+
+impl<T: Ring> GradeInvolution for Trivector<T> {
+    fn involute(&self) -> Self {
+        -*self
+    }
+}
+
+impl<T: Ring> Conjugate for Trivector<T> {
+    fn conjugate(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Ring> Reverse for Multivector<T> {
+    fn reverse(&self) -> Self {
+        Multivector::new(self.scalar, self.e1, self.e2, self.e3, -self.e12, -self.e23, -self.e31, -self.e123)
+    }
+}
+
+impl<T: Ring> GradeInvolution for Multivector<T> {
+    fn involute(&self) -> Self {
+        Multivector::new(self.scalar, -self.e1, -self.e2, -self.e3, self.e12, self.e23, self.e31, -self.e123)
+    }
+}
+
+impl<T: Ring> Conjugate for Multivector<T> {
+    fn conjugate(&self) -> Self {
+        Multivector::new(self.scalar, -self.e1, -self.e2, -self.e3, -self.e12, -self.e23, -self.e31, self.e123)
+    }
 }
 
+impl<T: Ring + PartialOrd> Grade<T> for Multivector<T> {
+    fn grade(&self, k: usize) -> Self {
+        let zero = Multivector::zero();
+        match k {
+            0 => Multivector { scalar: self.scalar, ..zero },
+            1 => Multivector { e1: self.e1, e2: self.e2, e3: self.e3, ..zero },
+            2 => Multivector { e12: self.e12, e23: self.e23, e31: self.e31, ..zero },
+            3 => Multivector { e123: self.e123, ..zero },
+            _ => zero
+        }
+    }
+
+    fn grades(&self, tolerance: T) -> Vec<usize> {
+        let parts = [
+            vec![self.scalar],
+            vec![self.e1, self.e2, self.e3],
+            vec![self.e12, self.e23, self.e31],
+            vec![self.e123]
+        ];
+        (0..4).filter(|&k| parts[k].iter().any(|&c| exceeds(c, tolerance))).collect()
+    }
+
+    /// Every homogeneous element of Cl(3,0) factors into vectors.
+    fn is_blade(&self, tolerance: T) -> bool {
+        self.is_homogeneous(tolerance)
+    }
+
+    /// In Cl(3,0) every non-zero element of a single parity is a versor.
+    fn is_versor(&self, tolerance: T) -> bool {
+        let grades = self.grades(tolerance);
+        !grades.is_empty() && grades.iter().all(|k| k % 2 == grades[0] % 2)
+    }
+}
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use approx::assert_close;
 
     #[test]
     fn test_vector_mag() {
@@ -176,8 +1141,28 @@ mod tests {
 
         let bivec: Bivector = vec1.wedgep(&vec2);
 
-        assert_eq!(&vec1, bivec.x);
-        assert_eq!(&vec2, bivec.y);
+        assert_eq!(Bivector::new(-1.0, 0.0, 0.0), bivec);
+    }
+
+    #[test]
+    fn test_wedgep_same_plane() {
+        let vec1 = Vector::new(1.0, 0.0, 0.0);
+        let vec2 = Vector::new(0.0, 2.0, 0.0);
+        let vec3 = Vector::new(2.0, 0.0, 0.0);
+        let vec4 = Vector::new(1.0, 1.0, 0.0);
+
+        assert_eq!(vec1.wedgep(&vec2), vec3.wedgep(&vec4));
+        assert!((vec1.wedgep(&vec2).mag() - FactoredBivector::from_vectors(&vec3, &vec4).mag()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_factored_bivector() {
+        let vec1 = Vector::new(0.0, 1.0, 2.0);
+        let vec2 = Vector::new(3.0, 0.0, 1.0);
+
+        let factored = FactoredBivector::from_vectors(&vec1, &vec2);
+
+        assert_eq!(vec1.wedgep(&vec2), factored.to_bivector());
     }
 
     #[test]
@@ -188,9 +1173,488 @@ mod tests {
         let sc: Scalar = vec1.innerp(&vec2);
         let bivec: Bivector = vec1.wedgep(&vec2);
 
-        let geoprod: (Scalar, Bivector) = vec1.geop(&vec2);
+        let geoprod: Multivector = vec1.geop(&vec2);
+
+        assert_eq!(Multivector::from(sc) + Multivector::from(bivec), geoprod);
+    }
+
+    #[test]
+    fn test_multivector_from() {
+        let vec1 = Vector::new(1.0, 2.0, 3.0);
+        let vec2 = Vector::new(1.0, 0.0, 0.0);
+        let vec3 = Vector::new(0.0, 1.0, 0.0);
+        let vec4 = Vector::new(0.0, 0.0, 1.0);
+
+        assert_eq!(Multivector::new(0.0, 1.0, 2.0, 3.0, 0.0, 0.0, 0.0, 0.0), Multivector::from(&vec1));
+        assert_eq!(Multivector::new(2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0), Multivector::from(Scalar { value: 2.0 }));
+        assert_eq!(Multivector::new(0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0), Multivector::from(vec2.wedgep(&vec3)));
+        assert_eq!(Multivector::new(0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0),
+            Multivector::from(Trivector::from_vectors(&vec2, &vec3, &vec4)));
+    }
+
+    #[test]
+    fn test_multivector_geop() {
+        let e1 = Multivector::from(Vector::new(1.0, 0.0, 0.0));
+        let e2 = Multivector::from(Vector::new(0.0, 1.0, 0.0));
+        let e3 = Multivector::from(Vector::new(0.0, 0.0, 1.0));
+
+        let e12 = e1.geop(&e2);
+        let e123 = e12.geop(&e3);
+
+        assert_eq!(Multivector::new(0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0), e12);
+        assert_eq!(Multivector::new(0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0), e123);
+        assert_eq!(Multivector::new(-1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0), e12.geop(&e12));
+        assert_eq!(Multivector::new(-1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0), e123.geop(&e123));
+    }
+
+    #[test]
+    fn test_geop_chain() {
+        let vec1 = Vector::new(1.0, 2.0, 0.0);
+        let vec2 = Vector::new(0.0, 1.0, 3.0);
+        let vec3 = Vector::new(2.0, 0.0, 1.0);
+
+        let left = vec1.geop(&vec2).geop(&Multivector::from(&vec3));
+        let right = Multivector::from(&vec1).geop(&vec2.geop(&vec3));
+
+        assert_eq!(left, right);
+    }
+
+    #[test]
+    fn test_trivector_volume() {
+        let vec1 = Vector::new(2.0, 0.0, 0.0);
+        let vec2 = Vector::new(1.0, 3.0, 0.0);
+        let vec3 = Vector::new(5.0, 7.0, 4.0);
+
+        let trivec = Trivector::from_vectors(&vec1, &vec2, &vec3);
+
+        assert_eq!(24.0, trivec.mag());
+        assert_eq!(vec1.innerp(&vec2.outerp(&vec3)).value, trivec.mag());
+        assert_eq!(-24.0, Trivector::from_vectors(&vec2, &vec1, &vec3).mag());
+    }
+
+    #[test]
+    fn test_trivector_orientation() {
+        let vec1 = Vector::new(1.0, 0.0, 0.0);
+        let vec2 = Vector::new(0.0, 1.0, 0.0);
+        let vec3 = Vector::new(0.0, 0.0, 1.0);
+
+        assert_eq!(Orientation::RightHanded, Trivector::from_vectors(&vec1, &vec2, &vec3).orientation());
+        assert_eq!(Orientation::LeftHanded, Trivector::from_vectors(&vec1, &vec3, &vec2).orientation());
+        assert_eq!(Orientation::Degenerate, Trivector::from_vectors(&vec1, &vec2, &vec1).orientation());
+    }
+
+    #[test]
+    fn test_pseudoscalar_dual() {
+        let vec1 = Vector::new(1.0, 2.0, 0.0);
+        let vec2 = Vector::new(0.0, 1.0, 3.0);
+
+        let i = Multivector::from(Trivector::pseudoscalar());
+
+        assert_eq!(Multivector::from(Scalar { value: -1.0 }), i.geop(&i));
+        assert_eq!(Multivector::from(vec1.outerp(&vec2)), Multivector::from(vec1.wedgep(&vec2)).dual());
+    }
+
+    #[test]
+    fn test_dual_matches_multivector_dual() {
+        let vec = Vector::new(1.0, 2.0, 3.0);
+        let bivec = Bivector::new(4.0, 5.0, 6.0);
+        let s = Scalar { value: 2.0 };
+        let t = Trivector::new(3.0);
+
+        assert_eq!(Multivector::from(vec).dual(), Multivector::from(vec.dual()));
+        assert_eq!(Multivector::from(bivec).dual(), Multivector::from(bivec.dual()));
+        assert_eq!(Multivector::from(s).dual(), Multivector::from(s.dual()));
+        assert_eq!(Multivector::from(t).dual(), Multivector::from(t.dual()));
+        assert_eq!(Multivector::from(bivec).undual(), Multivector::from(bivec.undual()));
+        assert_eq!(Multivector::from(vec).hodge(), Multivector::from(vec.hodge()));
+        assert_eq!(Multivector::from(bivec).hodge(), Multivector::from(bivec.hodge()));
+    }
+
+    #[test]
+    fn test_normal_and_plane_round_trip() {
+        let vec1 = Vector::new(1.0, 0.0, 0.0);
+        let vec2 = Vector::new(0.0, 1.0, 0.0);
+        let plane = vec1.wedgep(&vec2);
+
+        assert_eq!(Vector::new(0.0, 0.0, 1.0), plane.dual());
+        assert_eq!(plane, plane.dual().undual());
+        assert_eq!(plane, vec1.outerp(&vec2).undual());
+        assert_eq!(vec1.outerp(&vec2), plane.hodge());
+    }
+
+    #[test]
+    fn test_hodge_star() {
+        let m = Multivector::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0);
+        let i = Multivector::from(Trivector::pseudoscalar());
+
+        assert_eq!(m, m.undual().dual());
+        for k in 0..4 {
+            let a = m.grade(k);
+            let volume = a.wedgep(&a.hodge());
+            assert_eq!(i * a.reverse().geop(&a).scalar, volume);
+        }
+    }
+
+    #[test]
+    fn test_involution_signs() {
+        let m = Multivector::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0);
+
+        assert_eq!(Multivector::new(1.0, 2.0, 3.0, 4.0, -5.0, -6.0, -7.0, -8.0), m.reverse());
+        assert_eq!(Multivector::new(1.0, -2.0, -3.0, -4.0, 5.0, 6.0, 7.0, -8.0), m.involute());
+        assert_eq!(m.reverse().involute(), m.conjugate());
+        assert_eq!(m, m.reverse().reverse());
+        assert_eq!(m, m.involute().involute());
+        assert_eq!(m, m.conjugate().conjugate());
+    }
+
+    #[test]
+    fn test_involutions_match_graded_types() {
+        let vec1 = Vector::new(1.0, 2.0, 3.0);
+        let bivec = Bivector::new(1.0, -2.0, 0.5);
+        let trivec = Trivector::new(2.0);
+
+        assert_eq!(Multivector::from(vec1).reverse(), Multivector::from(vec1.reverse()));
+        assert_eq!(Multivector::from(vec1).involute(), Multivector::from(vec1.involute()));
+        assert_eq!(Multivector::from(bivec).reverse(), Multivector::from(bivec.reverse()));
+        assert_eq!(Multivector::from(bivec).conjugate(), Multivector::from(bivec.conjugate()));
+        assert_eq!(Multivector::from(trivec).involute(), Multivector::from(trivec.involute()));
+        assert_eq!(Multivector::from(trivec).conjugate(), Multivector::from(trivec.conjugate()));
+    }
+
+    #[test]
+    fn test_reverse_of_product() {
+        let a = Multivector::new(1.0, 2.0, -1.0, 0.5, 3.0, -2.0, 1.5, 4.0);
+        let b = Multivector::new(-2.0, 0.5, 1.0, 2.0, -1.0, 3.0, 0.25, -1.0);
+
+        assert_eq!(b.reverse().geop(&a.reverse()), a.geop(&b).reverse());
+        assert_eq!(a.involute().geop(&b.involute()), a.geop(&b).involute());
+        assert_eq!(b.conjugate().geop(&a.conjugate()), a.geop(&b).conjugate());
+    }
+
+    #[test]
+    fn test_reverse_of_vector_product() {
+        let vec1 = Vector::new(1.0, 2.0, 0.0);
+        let vec2 = Vector::new(0.0, 1.0, 3.0);
+
+        assert_eq!(vec2.geop(&vec1), vec1.geop(&vec2).reverse());
+        assert_eq!(vec2 ^ vec1, (vec1 ^ vec2).reverse());
+    }
+
+    #[test]
+    fn test_grade_projection() {
+        let m = Multivector::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0);
+
+        assert_eq!(Multivector::from(Vector::new(2.0, 3.0, 4.0)), m.grade(1));
+        assert_eq!(Multivector::from(Bivector::new(5.0, 6.0, 7.0)), m.grade(2));
+        assert_eq!(m, m.grade(0) + m.grade(1) + m.grade(2) + m.grade(3));
+        assert_eq!(Multivector::zero(), m.grade(4));
+    }
+
+    #[test]
+    fn test_grades_with_tolerance() {
+        let vec1 = Vector::new(1.0, 0.0, 0.0);
+        let vec2 = Vector::new(1e-15, 1.0, 0.0);
+
+        let prod = vec1.geop(&vec2);
+
+        assert_eq!(vec![0, 2], prod.grades(0.0));
+        assert_eq!(vec![2], prod.grades(1e-12));
+        assert!(prod.is_homogeneous(1e-12));
+        assert!(!prod.is_homogeneous(0.0));
+        assert_eq!(Vec::<usize>::new(), Multivector::zero().grades(0.0));
+    }
+
+    #[test]
+    fn test_blade_and_versor_queries() {
+        let vec1 = Vector::new(1.0, 2.0, 0.0);
+        let vec2 = Vector::new(0.0, 1.0, 3.0);
+
+        assert!(Multivector::from(vec1 ^ vec2).is_blade(0.0));
+        assert!(!(vec1 * vec2).is_blade(0.0));
+        assert!((vec1 * vec2).is_versor(0.0));
+        assert!((vec1 * vec2 * vec1).is_versor(0.0));
+        assert!(!(Multivector::from(vec1) + Scalar { value: 1.0 }).is_versor(0.0));
+        assert!(!Multivector::zero().is_versor(0.0));
+    }
+
+    #[test]
+    fn test_inner_products_on_vectors() {
+        let vec1 = Vector::new(1.0, 2.0, 0.0);
+        let vec2 = Vector::new(0.0, 1.0, 3.0);
+        let (a, b) = (Multivector::from(vec1), Multivector::from(vec2));
+        let dot = Multivector::from(vec1.innerp(&vec2));
+
+        assert_eq!(dot, a.left_contraction(&b));
+        assert_eq!(dot, a.right_contraction(&b));
+        assert_eq!(dot, a.scalar_product(&b));
+        assert_eq!(dot, a.hestenes(&b));
+        assert_eq!(dot, a.fat_dot(&b));
+    }
+
+    #[test]
+    fn test_inner_products_vector_and_bivector() {
+        let v = Multivector::from(Vector::new(1.0, 0.0, 0.0));
+        let b = Multivector::from(Bivector::new(1.0, 0.0, 0.0));
+        let e2 = Multivector::from(Vector::new(0.0, 1.0, 0.0));
+
+        assert_eq!(e2, v.left_contraction(&b));
+        assert_eq!(Multivector::zero(), b.left_contraction(&v));
+        assert_eq!(-e2, b.right_contraction(&v));
+        assert_eq!(Multivector::zero(), v.right_contraction(&b));
+        assert_eq!(Multivector::zero(), v.scalar_product(&b));
+        assert_eq!(e2, v.hestenes(&b));
+        assert_eq!(-e2, b.fat_dot(&v));
+    }
+
+    #[test]
+    fn test_inner_products_on_scalars() {
+        let s = Multivector::from(Scalar { value: 2.0 });
+        let m = Multivector::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0);
+        let only_scalar = Multivector { scalar: 2.0, ..Multivector::zero() };
+
+        assert_eq!(m * 2.0, s.left_contraction(&m));
+        assert_eq!(only_scalar, m.left_contraction(&s));
+        assert_eq!(m * 2.0, m.right_contraction(&s));
+        assert_eq!(only_scalar, s.right_contraction(&m));
+        assert_eq!(only_scalar, s.scalar_product(&m));
+        assert_eq!(Multivector::zero(), s.hestenes(&m));
+        assert_eq!(m * 2.0, s.fat_dot(&m));
+    }
+
+    #[test]
+    fn test_graded_inverses() {
+        let vec = Vector::new(1.0, 2.0, 2.0);
+        let bivec = Bivector::new(0.0, 3.0, 4.0);
+        let one = Multivector::from(Scalar { value: 1.0 });
+
+        assert_eq!(Vector::new(1.0 / 9.0, 2.0 / 9.0, 2.0 / 9.0), vec.inverse().unwrap());
+        assert_close(one, vec * vec.inverse().unwrap());
+        assert_close(one, bivec * bivec.inverse().unwrap());
+        assert_close(one, Trivector::new(2.0) * Trivector::new(2.0).inverse().unwrap());
+        assert_eq!(Some(Scalar { value: 0.5 }), Scalar { value: 2.0 }.inverse());
+        assert_eq!(None, Vector::new(0.0, 0.0, 0.0).inverse());
+        assert_eq!(None, Bivector::<f64>::default().inverse());
+        assert_eq!(None, Trivector::<f64>::default().inverse());
+    }
+
+    #[test]
+    fn test_multivector_inverse() {
+        let m = Multivector::new(1.0, 2.0, -1.0, 0.5, 3.0, -2.0, 1.5, 4.0);
+        let one = Multivector::from(Scalar { value: 1.0 });
+        let inv = m.inverse().unwrap();
+
+        assert_close(one, m * inv);
+        assert_close(one, inv * m);
+        assert_close(m, inv.inverse().unwrap());
+
+        let versor = Vector::new(1.0, 2.0, 0.0) * Vector::new(0.0, 1.0, 3.0);
+        assert_close(one, versor * versor.inverse().unwrap());
+    }
+
+    #[test]
+    fn test_non_invertible_multivector() {
+        let null = Multivector { scalar: 1.0, e1: 1.0, ..Multivector::zero() };
+
+        assert_eq!(None, null.inverse());
+        assert_eq!(None, Multivector::<f64>::zero().inverse());
+        assert_eq!(None, Multivector::from(Scalar { value: 1.0 }).div_left(&null));
+    }
+
+    #[test]
+    fn test_division() {
+        let a = Multivector::new(1.0, 2.0, -1.0, 0.5, 3.0, -2.0, 1.5, 4.0);
+        let b = Multivector::new(-2.0, 1.0, 0.5, 3.0, -1.0, 2.0, 0.5, -3.0);
+
+        assert_close(b, a * b.div_left(&a).unwrap());
+        assert_close(b, b.div_right(&a).unwrap() * a);
+    }
+
+    #[test]
+    fn test_project_onto_vector() {
+        let v = Vector::new(3.0, -1.0, 2.0);
+        let a = Vector::new(1.0, 2.0, 2.0);
+        let classical = a * (v.innerp(&a).value / a.innerp(&a).value);
+
+        assert_close(classical, v.project_onto(&a));
+        assert_close(v - classical, v.reject_from(&a));
+        assert_close(classical * 2.0 - v, v.reflect_in(&a));
+    }
+
+    #[test]
+    fn test_project_onto_plane() {
+        let v = Vector::new(3.0, -1.0, 2.0);
+        let (a, b) = (Vector::new(1.0, 2.0, 0.0), Vector::new(0.0, 1.0, 3.0));
+        let normal = a.outerp(&b);
+        let along_normal = normal * (v.innerp(&normal).value / normal.innerp(&normal).value);
+
+        assert_close(v - along_normal, v.project_onto(&a.wedgep(&b)));
+        assert_close(along_normal, v.reject_from(&a.wedgep(&b)));
+        assert_close(v - along_normal * 2.0, v.reflect_in(&a.wedgep(&b)));
+    }
+
+    #[test]
+    fn test_project_sign_conventions() {
+        let v = Vector::new(1.0, 1.0, 1.0);
+        let e12 = Bivector::new(1.0, 0.0, 0.0);
+
+        assert_eq!(Vector::new(1.0, 1.0, 0.0), v.project_onto(&e12));
+        assert_eq!(Vector::new(1.0, 1.0, 0.0), v.project_onto(&-e12));
+        assert_eq!(Vector::new(0.0, 0.0, 1.0), v.reject_from(&e12));
+        assert_eq!(Vector::new(1.0, 1.0, -1.0), v.reflect_in(&e12));
+        assert_eq!(Vector::new(1.0, -1.0, -1.0), v.reflect_in(&Vector::new(-2.0, 0.0, 0.0)));
+    }
+
+    #[test]
+    fn test_project_onto_zero_blade() {
+        let v = Vector::new(1.0, 2.0, 3.0);
+
+        assert_eq!(Vector::new(0.0, 0.0, 0.0), v.project_onto(&Bivector::default()));
+        assert_eq!(v, v.reject_from(&Vector::new(0.0, 0.0, 0.0)));
+    }
+
+    #[test]
+    fn test_meet_of_planes() {
+        let xy = Bivector::new(2.0, 0.0, 0.0);
+        let yz = Bivector::new(0.0, 1.0, 0.0);
+
+        let line = xy.meet(&yz);
+        let (_, join, dimension) = Multivector::from(xy).meet_join(&Multivector::from(yz), 1e-12);
+
+        assert_eq!(vec![1], line.grades(1e-12));
+        assert_close(Multivector::zero(), line.wedgep(&Multivector::from(Vector::new(0.0, 1.0, 0.0))));
+        assert_eq!(3, dimension);
+        assert_eq!(vec![3], join.grades(1e-12));
+    }
+
+    #[test]
+    fn test_meet_of_small_planes() {
+        let xy = Multivector::from(Bivector::new(1e-9, 0.0, 0.0));
+        let yz = Multivector::from(Bivector::new(0.0, 1e-9, 0.0));
+
+        let line = xy.meet(&yz);
+        assert!(line.mag() > 0.0);
+        assert_close(Multivector::from(Vector::new(0.0, 1.0, 0.0)), (line / line.mag()) * (line.e2 / line.e2.abs()));
+        assert_eq!(vec![3], xy.join(&yz).grades(1e-12));
+    }
+
+    #[test]
+    fn test_meet_of_coincident_planes() {
+        let plane = Vector::new(1.0, 2.0, 0.0).wedgep(&Vector::new(0.0, 1.0, 3.0));
+        let (meet, join, dimension) = Multivector::from(plane).meet_join(&Multivector::from(plane * 2.0), 1e-12);
+
+        assert_eq!(2, dimension);
+        assert_eq!(vec![2], meet.grades(1e-12));
+        assert_close(Multivector::zero(), meet.wedgep(&Multivector::from(Vector::new(1.0, 2.0, 0.0))));
+        assert_close(Multivector::from(plane / plane.mag()), join);
+    }
+
+    #[test]
+    fn test_meet_join_of_vectors() {
+        let a = Multivector::from(Vector::new(1.0, 2.0, 0.0));
+        let b = Multivector::from(Vector::new(0.0, 1.0, 3.0));
+
+        let (meet, join, dimension) = a.meet_join(&b, 1e-12);
+        assert_eq!(2, dimension);
+        assert_eq!(a.wedgep(&b), join);
+        assert_eq!(vec![0], meet.grades(1e-12));
+
+        let (meet, _, dimension) = a.meet_join(&(a * -3.0), 1e-12);
+        assert_eq!(1, dimension);
+        assert_eq!(vec![1], meet.grades(1e-12));
+    }
+
+    #[test]
+    fn test_meet_join_of_vector_and_plane() {
+        let plane = Multivector::from(Bivector::new(1.0, 0.0, 0.0));
+        let inside = Multivector::from(Vector::new(1.0, 1.0, 0.0));
+        let outside = Multivector::from(Vector::new(1.0, 1.0, 1.0));
+
+        let (meet, join, dimension) = inside.meet_join(&plane, 1e-12);
+        assert_eq!(2, dimension);
+        assert_eq!(vec![2], join.grades(1e-12));
+        assert_close(Multivector::zero(), meet.wedgep(&inside));
+
+        assert_eq!(3, outside.meet_join(&plane, 1e-12).2);
+        assert_eq!(vec![3], outside.join(&plane).grades(1e-12));
+    }
+
+    #[test]
+    fn test_bivector_factor() {
+        let bivec = Vector::new(1.0, 2.0, 0.0).wedgep(&Vector::new(0.0, 1.0, 3.0));
+        let (a, b) = bivec.factor().unwrap();
+
+        assert!((a.mag() - 1.0).abs() < 1e-12);
+        assert!((b.mag() - bivec.mag()).abs() < 1e-12);
+        assert!(a.innerp(&b).value.abs() < 1e-12);
+        assert!((a.wedgep(&b) - bivec).mag() < 1e-12);
+        assert_eq!(None, Bivector::<f64>::default().factor());
+    }
+
+    #[test]
+    fn test_bivector_factor_orientation() {
+        let (a, b) = Bivector::new(-2.0, 0.0, 0.0).factor().unwrap();
+
+        assert_eq!(Bivector::new(-2.0, 0.0, 0.0), a.wedgep(&b));
+        assert_eq!(Vector::new(0.0, -1.0, 0.0), a);
+        assert_eq!(Vector::new(-2.0, 0.0, 0.0), b);
+    }
+
+    #[test]
+    fn test_trivector_factor() {
+        let trivec = Trivector::new(-3.0);
+        let (a, b, c) = trivec.factor().unwrap();
+
+        assert_eq!(trivec, Trivector::from_vectors(&a, &b, &c));
+        assert_eq!(0.0, a.innerp(&b).value + b.innerp(&c).value + c.innerp(&a).value);
+        assert_eq!(None, Trivector::<f64>::default().factor());
+    }
+
+    #[test]
+    fn test_reflection_counts() {
+        let rotor = Multivector::from(Rotor::from_axis_angle(&Vector::new(1.0, 2.0, 2.0), 0.9));
+        let mirror = Multivector::from(Vector::new(0.0, 3.0, 4.0));
+        let inversion = Multivector::from(Trivector::pseudoscalar());
+        let one = Multivector::from(Scalar { value: 1.0 });
+
+        assert_eq!(0, one.reflections(1e-9).unwrap().len());
+        assert_eq!(1, mirror.reflections(1e-9).unwrap().len());
+        assert_eq!(2, rotor.reflections(1e-9).unwrap().len());
+        assert_eq!(3, rotor.geop(&mirror).reflections(1e-9).unwrap().len());
+        assert_eq!(3, inversion.reflections(1e-9).unwrap().len());
+        assert_eq!(None, (one + Vector::new(1.0, 0.0, 0.0)).reflections(1e-9));
+    }
+
+    #[test]
+    fn test_reflections_reproduce_versor() {
+        let rotor = Multivector::from(Rotor::from_axis_angle(&Vector::new(1.0, 2.0, 2.0), 0.9));
+        let versors = [
+            rotor * -2.0,
+            rotor.geop(&Multivector::from(Vector::new(0.0, 3.0, 4.0))),
+            Multivector::from(Trivector::new(-2.0)),
+            Multivector::from(Scalar { value: -3.0 })
+        ];
+
+        for v in versors.iter() {
+            assert_close(*v, versor_product(&v.reflections(1e-9).unwrap()));
+        }
+    }
+
+    #[test]
+    fn test_versor_from_orthogonal_matrix() {
+        let rotation = [[0.0, -1.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, 1.0]];
+        let reflection = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, -1.0]];
+        let x = Multivector::from(Vector::new(1.0, 0.0, 0.0));
+        let z = Multivector::from(Vector::new(0.0, 0.0, 1.0));
+
+        let rotor = Multivector::from_orthogonal_matrix(&rotation, 1e-12).unwrap();
+        assert_eq!(vec![0, 2], rotor.grades(1e-12));
+        assert_close(Multivector::from(Vector::new(0.0, 1.0, 0.0)), rotor * x * rotor.reverse());
+
+        let mirror = Multivector::from_orthogonal_matrix(&reflection, 1e-12).unwrap();
+        assert_eq!(vec![1], mirror.grades(1e-12));
+        // An odd versor acts as x ↦ -V x Ṽ, so M z = -z means V z Ṽ = z.
+        assert_close(z, mirror * z * mirror.reverse());
 
-        assert_eq!(sc.value, geoprod.0.value);
-        assert_eq!(bivec, geoprod.1);
+        assert_eq!(None, Multivector::from_orthogonal_matrix(&[[2.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]], 1e-12));
     }
 }
@@ -0,0 +1,369 @@
+//! Operator overloads for the Cl(3,0) element types, for owned values and
+//! references alike.
+//!
+//! `+` and `-` add like elements and otherwise produce a `Multivector`, `*`
+//! is the geometric product (or scaling, by a scalar), `^` is the wedge
+//! product, `|` is the left contraction and `!` is the dual A I⁻¹. Products
+//! whose grade is known in advance keep the narrower type.
+
+use std::ops::{Add, BitOr, BitXor, Div, Mul, Neg, Not, Sub};
+
+use {Bivector, Dual, GeometricProduct, InnerProduct, Multivector, Ring, Scalar, Trivector, Vector, WedgeProduct};
+
+/// Implements `$Op` for every owned/borrowed combination of `$Lhs` and
+/// `$Rhs`, all of them forwarding to the owned case.
+macro_rules! binop {
+    ($Op:ident, $op:ident, $Lhs:ident, $Rhs:ident, $Out:ident, |$a:ident, $b:ident| $body:expr) => {
+        impl<T: Ring> $Op<$Rhs<T>> for $Lhs<T> {
+            type Output = $Out<T>;
+
+            fn $op(self, rhs: $Rhs<T>) -> $Out<T> {
+                let ($a, $b) = (self, rhs);
+                $body
+            }
+        }
+
+        impl<'b, T: Ring> $Op<&'b $Rhs<T>> for $Lhs<T> {
+            type Output = $Out<T>;
+
+            fn $op(self, rhs: &'b $Rhs<T>) -> $Out<T> {
+                $Op::$op(self, *rhs)
+            }
+        }
+
+        impl<'a, T: Ring> $Op<$Rhs<T>> for &'a $Lhs<T> {
+            type Output = $Out<T>;
+
+            fn $op(self, rhs: $Rhs<T>) -> $Out<T> {
+                $Op::$op(*self, rhs)
+            }
+        }
+
+        impl<'a, 'b, T: Ring> $Op<&'b $Rhs<T>> for &'a $Lhs<T> {
+            type Output = $Out<T>;
+
+            fn $op(self, rhs: &'b $Rhs<T>) -> $Out<T> {
+                $Op::$op(*self, *rhs)
+            }
+        }
+    }
+}
+
+/// Addition and subtraction of unlike elements, which give a multivector.
+macro_rules! mixed {
+    ($Op:ident, $op:ident, $Lhs:ident, $Rhs:ident, |$a:ident, $b:ident| $body:expr) => {
+        binop!($Op, $op, $Lhs, $Rhs, Multivector, |$a, $b| $body);
+    }
+}
+
+/// Componentwise addition, subtraction, negation and scaling for an
+/// element type with the given coefficient fields.
+macro_rules! linear {
+    ($Type:ident, $($field:ident),+) => {
+        binop!(Add, add, $Type, $Type, $Type, |a, b| $Type { $($field: a.$field + b.$field),+ });
+        binop!(Sub, sub, $Type, $Type, $Type, |a, b| $Type { $($field: a.$field - b.$field),+ });
+
+        impl<T: Ring> Neg for $Type<T> {
+            type Output = $Type<T>;
+
+            fn neg(self) -> $Type<T> {
+                $Type { $($field: -self.$field),+ }
+            }
+        }
+
+        impl<'a, T: Ring> Neg for &'a $Type<T> {
+            type Output = $Type<T>;
+
+            fn neg(self) -> $Type<T> {
+                -*self
+            }
+        }
+
+        impl<T: Ring> Mul<T> for $Type<T> {
+            type Output = $Type<T>;
+
+            fn mul(self, k: T) -> $Type<T> {
+                $Type { $($field: self.$field * k),+ }
+            }
+        }
+
+        impl<'a, T: Ring> Mul<T> for &'a $Type<T> {
+            type Output = $Type<T>;
+
+            fn mul(self, k: T) -> $Type<T> {
+                *self * k
+            }
+        }
+
+        impl<T: Ring + Div<Output = T>> Div<T> for $Type<T> {
+            type Output = $Type<T>;
+
+            fn div(self, k: T) -> $Type<T> {
+                $Type { $($field: self.$field / k),+ }
+            }
+        }
+
+        impl<'a, T: Ring + Div<Output = T>> Div<T> for &'a $Type<T> {
+            type Output = $Type<T>;
+
+            fn div(self, k: T) -> $Type<T> {
+                *self / k
+            }
+        }
+
+        impl Mul<$Type<f64>> for f64 {
+            type Output = $Type<f64>;
+
+            fn mul(self, v: $Type<f64>) -> $Type<f64> {
+                v * self
+            }
+        }
+
+        impl Mul<$Type<f32>> for f32 {
+            type Output = $Type<f32>;
+
+            fn mul(self, v: $Type<f32>) -> $Type<f32> {
+                v * self
+            }
+        }
+    }
+}
+
+/// The dual A I⁻¹, mapping each grade to its complement.
+macro_rules! dual {
+    ($Type:ident, $Out:ident) => {
+        impl<T: Ring> Not for $Type<T> {
+            type Output = $Out<T>;
+
+            fn not(self) -> $Out<T> {
+                Dual::dual(&self)
+            }
+        }
+
+        impl<'a, T: Ring> Not for &'a $Type<T> {
+            type Output = $Out<T>;
+
+            fn not(self) -> $Out<T> {
+                !*self
+            }
+        }
+    }
+}
+
+linear!(Scalar, value);
+linear!(Vector, x, y, z);
+linear!(Bivector, e12, e23, e31);
+linear!(Trivector, e123);
+linear!(Multivector, scalar, e1, e2, e3, e12, e23, e31, e123);
+
+mixed!(Add, add, Scalar, Vector, |a, b| Multivector::from(a) + Multivector::from(b));
+mixed!(Sub, sub, Scalar, Vector, |a, b| Multivector::from(a) - Multivector::from(b));
+mixed!(Add, add, Scalar, Bivector, |a, b| Multivector::from(a) + Multivector::from(b));
+mixed!(Sub, sub, Scalar, Bivector, |a, b| Multivector::from(a) - Multivector::from(b));
+mixed!(Add, add, Scalar, Trivector, |a, b| Multivector::from(a) + Multivector::from(b));
+mixed!(Sub, sub, Scalar, Trivector, |a, b| Multivector::from(a) - Multivector::from(b));
+mixed!(Add, add, Scalar, Multivector, |a, b| Multivector::from(a) + b);
+mixed!(Sub, sub, Scalar, Multivector, |a, b| Multivector::from(a) - b);
+mixed!(Add, add, Vector, Scalar, |a, b| Multivector::from(a) + Multivector::from(b));
+mixed!(Sub, sub, Vector, Scalar, |a, b| Multivector::from(a) - Multivector::from(b));
+mixed!(Add, add, Vector, Bivector, |a, b| Multivector::from(a) + Multivector::from(b));
+mixed!(Sub, sub, Vector, Bivector, |a, b| Multivector::from(a) - Multivector::from(b));
+mixed!(Add, add, Vector, Trivector, |a, b| Multivector::from(a) + Multivector::from(b));
+mixed!(Sub, sub, Vector, Trivector, |a, b| Multivector::from(a) - Multivector::from(b));
+mixed!(Add, add, Vector, Multivector, |a, b| Multivector::from(a) + b);
+mixed!(Sub, sub, Vector, Multivector, |a, b| Multivector::from(a) - b);
+mixed!(Add, add, Bivector, Scalar, |a, b| Multivector::from(a) + Multivector::from(b));
+mixed!(Sub, sub, Bivector, Scalar, |a, b| Multivector::from(a) - Multivector::from(b));
+mixed!(Add, add, Bivector, Vector, |a, b| Multivector::from(a) + Multivector::from(b));
+mixed!(Sub, sub, Bivector, Vector, |a, b| Multivector::from(a) - Multivector::from(b));
+mixed!(Add, add, Bivector, Trivector, |a, b| Multivector::from(a) + Multivector::from(b));
+mixed!(Sub, sub, Bivector, Trivector, |a, b| Multivector::from(a) - Multivector::from(b));
+mixed!(Add, add, Bivector, Multivector, |a, b| Multivector::from(a) + b);
+mixed!(Sub, sub, Bivector, Multivector, |a, b| Multivector::from(a) - b);
+mixed!(Add, add, Trivector, Scalar, |a, b| Multivector::from(a) + Multivector::from(b));
+mixed!(Sub, sub, Trivector, Scalar, |a, b| Multivector::from(a) - Multivector::from(b));
+mixed!(Add, add, Trivector, Vector, |a, b| Multivector::from(a) + Multivector::from(b));
+mixed!(Sub, sub, Trivector, Vector, |a, b| Multivector::from(a) - Multivector::from(b));
+mixed!(Add, add, Trivector, Bivector, |a, b| Multivector::from(a) + Multivector::from(b));
+mixed!(Sub, sub, Trivector, Bivector, |a, b| Multivector::from(a) - Multivector::from(b));
+mixed!(Add, add, Trivector, Multivector, |a, b| Multivector::from(a) + b);
+mixed!(Sub, sub, Trivector, Multivector, |a, b| Multivector::from(a) - b);
+mixed!(Add, add, Multivector, Scalar, |a, b| a + Multivector::from(b));
+mixed!(Sub, sub, Multivector, Scalar, |a, b| a - Multivector::from(b));
+mixed!(Add, add, Multivector, Vector, |a, b| a + Multivector::from(b));
+mixed!(Sub, sub, Multivector, Vector, |a, b| a - Multivector::from(b));
+mixed!(Add, add, Multivector, Bivector, |a, b| a + Multivector::from(b));
+mixed!(Sub, sub, Multivector, Bivector, |a, b| a - Multivector::from(b));
+mixed!(Add, add, Multivector, Trivector, |a, b| a + Multivector::from(b));
+mixed!(Sub, sub, Multivector, Trivector, |a, b| a - Multivector::from(b));
+
+binop!(Mul, mul, Scalar, Scalar, Scalar, |a, b| b * a.value);
+binop!(Mul, mul, Scalar, Vector, Vector, |a, b| b * a.value);
+binop!(Mul, mul, Scalar, Bivector, Bivector, |a, b| b * a.value);
+binop!(Mul, mul, Scalar, Trivector, Trivector, |a, b| b * a.value);
+binop!(Mul, mul, Scalar, Multivector, Multivector, |a, b| Multivector::from(a).geop(&b));
+binop!(Mul, mul, Vector, Scalar, Vector, |a, b| a * b.value);
+binop!(Mul, mul, Vector, Vector, Multivector, |a, b| Multivector::from(a).geop(&Multivector::from(b)));
+binop!(Mul, mul, Vector, Bivector, Multivector, |a, b| Multivector::from(a).geop(&Multivector::from(b)));
+binop!(Mul, mul, Vector, Trivector, Multivector, |a, b| Multivector::from(a).geop(&Multivector::from(b)));
+binop!(Mul, mul, Vector, Multivector, Multivector, |a, b| Multivector::from(a).geop(&b));
+binop!(Mul, mul, Bivector, Scalar, Bivector, |a, b| a * b.value);
+binop!(Mul, mul, Bivector, Vector, Multivector, |a, b| Multivector::from(a).geop(&Multivector::from(b)));
+binop!(Mul, mul, Bivector, Bivector, Multivector, |a, b| Multivector::from(a).geop(&Multivector::from(b)));
+binop!(Mul, mul, Bivector, Trivector, Multivector, |a, b| Multivector::from(a).geop(&Multivector::from(b)));
+binop!(Mul, mul, Bivector, Multivector, Multivector, |a, b| Multivector::from(a).geop(&b));
+binop!(Mul, mul, Trivector, Scalar, Trivector, |a, b| a * b.value);
+binop!(Mul, mul, Trivector, Vector, Multivector, |a, b| Multivector::from(a).geop(&Multivector::from(b)));
+binop!(Mul, mul, Trivector, Bivector, Multivector, |a, b| Multivector::from(a).geop(&Multivector::from(b)));
+binop!(Mul, mul, Trivector, Trivector, Multivector, |a, b| Multivector::from(a).geop(&Multivector::from(b)));
+binop!(Mul, mul, Trivector, Multivector, Multivector, |a, b| Multivector::from(a).geop(&b));
+binop!(Mul, mul, Multivector, Scalar, Multivector, |a, b| a.geop(&Multivector::from(b)));
+binop!(Mul, mul, Multivector, Vector, Multivector, |a, b| a.geop(&Multivector::from(b)));
+binop!(Mul, mul, Multivector, Bivector, Multivector, |a, b| a.geop(&Multivector::from(b)));
+binop!(Mul, mul, Multivector, Trivector, Multivector, |a, b| a.geop(&Multivector::from(b)));
+binop!(Mul, mul, Multivector, Multivector, Multivector, |a, b| a.geop(&b));
+
+binop!(BitXor, bitxor, Scalar, Scalar, Scalar, |a, b| Mul::mul(b, a.value));
+binop!(BitXor, bitxor, Scalar, Vector, Vector, |a, b| Mul::mul(b, a.value));
+binop!(BitXor, bitxor, Scalar, Bivector, Bivector, |a, b| Mul::mul(b, a.value));
+binop!(BitXor, bitxor, Scalar, Trivector, Trivector, |a, b| Mul::mul(b, a.value));
+binop!(BitXor, bitxor, Scalar, Multivector, Multivector, |a, b| Multivector::from(a).wedgep(&b));
+binop!(BitXor, bitxor, Vector, Scalar, Vector, |a, b| Mul::mul(a, b.value));
+binop!(BitXor, bitxor, Vector, Vector, Bivector, |a, b| a.wedgep(&b));
+binop!(BitXor, bitxor, Vector, Bivector, Trivector, |a, b| a.wedgep(&b));
+binop!(BitXor, bitxor, Vector, Trivector, Multivector, |a, b| Multivector::from(a).wedgep(&Multivector::from(b)));
+binop!(BitXor, bitxor, Vector, Multivector, Multivector, |a, b| Multivector::from(a).wedgep(&b));
+binop!(BitXor, bitxor, Bivector, Scalar, Bivector, |a, b| Mul::mul(a, b.value));
+binop!(BitXor, bitxor, Bivector, Vector, Trivector, |a, b| a.wedgep(&b));
+binop!(BitXor, bitxor, Bivector, Bivector, Multivector, |a, b| Multivector::from(a).wedgep(&Multivector::from(b)));
+binop!(BitXor, bitxor, Bivector, Trivector, Multivector, |a, b| Multivector::from(a).wedgep(&Multivector::from(b)));
+binop!(BitXor, bitxor, Bivector, Multivector, Multivector, |a, b| Multivector::from(a).wedgep(&b));
+binop!(BitXor, bitxor, Trivector, Scalar, Trivector, |a, b| Mul::mul(a, b.value));
+binop!(BitXor, bitxor, Trivector, Vector, Multivector, |a, b| Multivector::from(a).wedgep(&Multivector::from(b)));
+binop!(BitXor, bitxor, Trivector, Bivector, Multivector, |a, b| Multivector::from(a).wedgep(&Multivector::from(b)));
+binop!(BitXor, bitxor, Trivector, Trivector, Multivector, |a, b| Multivector::from(a).wedgep(&Multivector::from(b)));
+binop!(BitXor, bitxor, Trivector, Multivector, Multivector, |a, b| Multivector::from(a).wedgep(&b));
+binop!(BitXor, bitxor, Multivector, Scalar, Multivector, |a, b| a.wedgep(&Multivector::from(b)));
+binop!(BitXor, bitxor, Multivector, Vector, Multivector, |a, b| a.wedgep(&Multivector::from(b)));
+binop!(BitXor, bitxor, Multivector, Bivector, Multivector, |a, b| a.wedgep(&Multivector::from(b)));
+binop!(BitXor, bitxor, Multivector, Trivector, Multivector, |a, b| a.wedgep(&Multivector::from(b)));
+binop!(BitXor, bitxor, Multivector, Multivector, Multivector, |a, b| a.wedgep(&b));
+
+binop!(BitOr, bitor, Scalar, Scalar, Scalar, |a, b| Mul::mul(b, a.value));
+binop!(BitOr, bitor, Scalar, Vector, Vector, |a, b| Mul::mul(b, a.value));
+binop!(BitOr, bitor, Scalar, Bivector, Bivector, |a, b| Mul::mul(b, a.value));
+binop!(BitOr, bitor, Scalar, Trivector, Trivector, |a, b| Mul::mul(b, a.value));
+binop!(BitOr, bitor, Scalar, Multivector, Multivector, |a, b| Multivector::from(a).innerp(&b));
+binop!(BitOr, bitor, Vector, Scalar, Multivector, |a, b| Multivector::from(a).innerp(&Multivector::from(b)));
+binop!(BitOr, bitor, Vector, Vector, Scalar, |a, b| a.innerp(&b));
+binop!(BitOr, bitor, Vector, Bivector, Vector, |a, b| Multivector::from(a).innerp(&Multivector::from(b)).vector());
+binop!(BitOr, bitor, Vector, Trivector, Multivector, |a, b| Multivector::from(a).innerp(&Multivector::from(b)));
+binop!(BitOr, bitor, Vector, Multivector, Multivector, |a, b| Multivector::from(a).innerp(&b));
+binop!(BitOr, bitor, Bivector, Scalar, Multivector, |a, b| Multivector::from(a).innerp(&Multivector::from(b)));
+binop!(BitOr, bitor, Bivector, Vector, Multivector, |a, b| Multivector::from(a).innerp(&Multivector::from(b)));
+binop!(BitOr, bitor, Bivector, Bivector, Multivector, |a, b| Multivector::from(a).innerp(&Multivector::from(b)));
+binop!(BitOr, bitor, Bivector, Trivector, Multivector, |a, b| Multivector::from(a).innerp(&Multivector::from(b)));
+binop!(BitOr, bitor, Bivector, Multivector, Multivector, |a, b| Multivector::from(a).innerp(&b));
+binop!(BitOr, bitor, Trivector, Scalar, Multivector, |a, b| Multivector::from(a).innerp(&Multivector::from(b)));
+binop!(BitOr, bitor, Trivector, Vector, Multivector, |a, b| Multivector::from(a).innerp(&Multivector::from(b)));
+binop!(BitOr, bitor, Trivector, Bivector, Multivector, |a, b| Multivector::from(a).innerp(&Multivector::from(b)));
+binop!(BitOr, bitor, Trivector, Trivector, Multivector, |a, b| Multivector::from(a).innerp(&Multivector::from(b)));
+binop!(BitOr, bitor, Trivector, Multivector, Multivector, |a, b| Multivector::from(a).innerp(&b));
+binop!(BitOr, bitor, Multivector, Scalar, Multivector, |a, b| a.innerp(&Multivector::from(b)));
+binop!(BitOr, bitor, Multivector, Vector, Multivector, |a, b| a.innerp(&Multivector::from(b)));
+binop!(BitOr, bitor, Multivector, Bivector, Multivector, |a, b| a.innerp(&Multivector::from(b)));
+binop!(BitOr, bitor, Multivector, Trivector, Multivector, |a, b| a.innerp(&Multivector::from(b)));
+binop!(BitOr, bitor, Multivector, Multivector, Multivector, |a, b| a.innerp(&b));
+
+dual!(Scalar, Trivector);
+dual!(Vector, Bivector);
+dual!(Bivector, Vector);
+dual!(Trivector, Scalar);
+dual!(Multivector, Multivector);
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use OuterProduct;
+
+    #[test]
+    fn test_linear_ops() {
+        let vec1 = Vector::new(1.0, 2.0, 3.0);
+        let vec2 = Vector::new(0.5, -1.0, 2.0);
+
+        assert_eq!(Vector::new(1.5, 1.0, 5.0), vec1 + vec2);
+        assert_eq!(Vector::new(0.5, 3.0, 1.0), vec1 - vec2);
+        assert_eq!(Vector::new(-1.0, -2.0, -3.0), -vec1);
+        assert_eq!(Vector::new(2.0, 4.0, 6.0), 2.0 * vec1);
+        assert_eq!(Vector::new(0.5, 1.0, 1.5), vec1 / 2.0);
+    }
+
+    #[test]
+    fn test_mixed_addition() {
+        let sc = Scalar { value: 2.0 };
+        let bivec = Bivector::new(1.0, 0.0, -1.0);
+
+        assert_eq!(Multivector::new(2.0, 0.0, 0.0, 0.0, 1.0, 0.0, -1.0, 0.0), sc + bivec);
+        assert_eq!(Multivector::new(-2.0, 0.0, 0.0, 0.0, 1.0, 0.0, -1.0, 0.0), bivec - sc);
+        assert_eq!(sc + bivec + Trivector::new(3.0), Multivector::new(2.0, 0.0, 0.0, 0.0, 1.0, 0.0, -1.0, 3.0));
+    }
+
+    #[test]
+    fn test_geometric_mul() {
+        let vec1 = Vector::new(1.0, 2.0, 0.0);
+        let vec2 = Vector::new(0.0, 1.0, 3.0);
+        let vec3 = Vector::new(2.0, 0.0, 1.0);
+
+        assert_eq!(vec1.geop(&vec2), vec1 * vec2);
+        assert_eq!(vec1.geop(&vec2).geop(&Multivector::from(vec3)), vec1 * vec2 * vec3);
+        assert_eq!(Vector::new(2.0, 4.0, 0.0), Scalar { value: 2.0 } * vec1);
+    }
+
+    #[test]
+    fn test_wedge_and_contraction() {
+        let vec1 = Vector::new(1.0, 2.0, 0.0);
+        let vec2 = Vector::new(0.0, 1.0, 3.0);
+        let vec3 = Vector::new(2.0, 0.0, 1.0);
+
+        assert_eq!(vec1.wedgep(&vec2), vec1 ^ vec2);
+        assert_eq!(Trivector::from_vectors(&vec1, &vec2, &vec3), vec1 ^ vec2 ^ vec3);
+        assert_eq!(2.0, (vec1 | vec2).value);
+        assert_eq!(vec1 * vec2, Multivector::from(vec1 | vec2) + (vec1 ^ vec2));
+        assert_eq!(Multivector::from(vec1) ^ Multivector::from(vec2), Multivector::from(vec1 ^ vec2));
+    }
+
+    #[test]
+    fn test_vector_contracts_bivector() {
+        let e1 = Vector::new(1.0, 0.0, 0.0);
+        let e2 = Vector::new(0.0, 1.0, 0.0);
+
+        assert_eq!(e2, e1 | (e1 ^ e2));
+        assert_eq!(Multivector::zero(), (e1 ^ e2) | e1);
+    }
+
+    #[test]
+    fn test_borrowed_operands() {
+        fn formula(a: &Vector, b: &Vector, c: &Bivector) -> Multivector {
+            a * b + (a ^ b) - c * a + !c * 2.0
+        }
+
+        let vec1 = Vector::new(1.0, 2.0, 0.0);
+        let vec2 = Vector::new(0.0, 1.0, 3.0);
+        let bivec = Bivector::new(1.0, -1.0, 0.5);
+
+        let expected = vec1 * vec2 + (vec1 ^ vec2) - bivec * vec1 + !bivec * 2.0;
+        assert_eq!(expected, formula(&vec1, &vec2, &bivec));
+    }
+
+    #[test]
+    fn test_dual() {
+        let vec1 = Vector::new(1.0, 2.0, 0.0);
+        let vec2 = Vector::new(0.0, 1.0, 3.0);
+
+        assert_eq!(vec1.outerp(&vec2), !(vec1 ^ vec2));
+        assert_eq!(Multivector::from(!vec1), !Multivector::from(vec1));
+        assert_eq!(Scalar { value: 1.0 }, !Trivector::new(1.0));
+        assert_eq!(vec1, !!!!vec1);
+    }
+}
@@ -0,0 +1,556 @@
+//! Projective geometric algebra Cl(3,0,1) for points, lines and planes.
+//!
+//! Planes are vectors a e1 + b e2 + c e3 + d e0 for ax + by + cz + d = 0,
+//! lines are bivectors and points are trivectors. The null basis vector e0
+//! is the fourth basis vector of `clifford::Pga3`. Meet is the outer
+//! product and join is the regressive product.
+
+use clifford;
+use {Bivector, GeometricProduct, Inverse, Join, Magnitude, Meet, Multivector, Project, RealField, Reverse, Ring, Rotor, Scalar, Vector, WedgeProduct};
+
+/// `clifford::Pga3` over any scalar type.
+type Pga<T> = clifford::Multivector<3, 0, 1, T>;
+
+const E0: usize = 0b1000;
+const E123: usize = 0b0111;
+const E023: usize = 0b1110;
+const E013: usize = 0b1101;
+const E012: usize = 0b1011;
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Plane<T = f64>(pub Pga<T>);
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Line<T = f64>(pub Pga<T>);
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Point<T = f64>(pub Pga<T>);
+
+/// An even element of Cl(3,0,1) acting as a rigid transform through the
+/// sandwich product M X M̃.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Motor<T = f64>(pub Pga<T>);
+
+impl<T: Ring> Plane<T> {
+    /// The plane ax + by + cz + d = 0.
+    pub fn new(a: T, b: T, c: T, d: T) -> Self {
+        Plane(Pga::from_vector(&[a, b, c, d]))
+    }
+
+    pub fn from_points(a: &Point<T>, b: &Point<T>, c: &Point<T>) -> Self {
+        a.join(b).join(c)
+    }
+
+    pub fn normal(&self) -> Vector<T> {
+        Vector::new(self.0.get(0b001), self.0.get(0b010), self.0.get(0b100))
+    }
+}
+
+impl<T: Ring> Point<T> {
+    pub fn new(x: T, y: T, z: T) -> Self {
+        let (zero, one) = (T::zero(), T::one());
+        Plane::new(one, zero, zero, -x)
+            .meet(&Plane::new(zero, one, zero, -y))
+            .meet(&Plane::new(zero, zero, one, -z))
+    }
+
+    /// The point at infinity in direction `d`.
+    pub fn direction(d: &Vector<T>) -> Self {
+        let mut mv = Pga::zero();
+        mv.set(E023, -d.x);
+        mv.set(E013, d.y);
+        mv.set(E012, -d.z);
+        Point(mv)
+    }
+
+    /// The direction of an ideal point, inverting `Point::direction`.
+    pub fn to_direction(&self) -> Vector<T> {
+        Vector::new(-self.0.get(E023), self.0.get(E013), -self.0.get(E012))
+    }
+}
+
+impl<T: RealField> Point<T> {
+    /// Whether the homogeneous weight is within `tolerance` of zero, so the
+    /// point lies at infinity.
+    pub fn is_ideal(&self, tolerance: T) -> bool {
+        self.0.get(E123).abs() <= tolerance
+    }
+
+    /// Euclidean coordinates of a finite point, after dividing out the
+    /// homogeneous weight, or `None` for an ideal point of zero weight.
+    pub fn to_vector(&self) -> Option<Vector<T>> {
+        let w = self.0.get(E123);
+        if w.is_zero() {
+            return None;
+        }
+        Some(Vector::new(-self.0.get(E023) / w, self.0.get(E013) / w, -self.0.get(E012) / w))
+    }
+}
+
+impl<'a, T: Ring> From<&'a Vector<T>> for Point<T> {
+    fn from(v: &'a Vector<T>) -> Self {
+        Point::new(v.x, v.y, v.z)
+    }
+}
+
+impl<T: Ring> From<Vector<T>> for Point<T> {
+    fn from(v: Vector<T>) -> Self {
+        Point::from(&v)
+    }
+}
+
+impl<T: Ring> Line<T> {
+    pub fn from_points(a: &Point<T>, b: &Point<T>) -> Self {
+        a.join(b)
+    }
+}
+
+impl<T: Ring> Motor<T> {
+    pub fn identity() -> Self {
+        Motor(Pga::scalar(T::one()))
+    }
+
+    /// The motor applying `self` first and `next` afterwards.
+    pub fn then(&self, next: &Motor<T>) -> Motor<T> {
+        Motor(next.0.geop(&self.0))
+    }
+
+    fn sandwich(&self, x: &Pga<T>) -> Pga<T> {
+        self.0.geop(x).geop(&self.0.reverse())
+    }
+
+    pub fn apply_point(&self, p: &Point<T>) -> Point<T> {
+        Point(self.sandwich(&p.0))
+    }
+
+    pub fn apply_line(&self, l: &Line<T>) -> Line<T> {
+        Line(self.sandwich(&l.0))
+    }
+
+    pub fn apply_plane(&self, p: &Plane<T>) -> Plane<T> {
+        Plane(self.sandwich(&p.0))
+    }
+}
+
+impl<T: RealField> Motor<T> {
+    /// The translator 1 - ½ e0 t, the product of reflections in two
+    /// parallel planes half of `t` apart.
+    pub fn translation(t: &Vector<T>) -> Self {
+        let e0 = Pga::blade(E0, T::one());
+        let t = Pga::from_vector(&[t.x, t.y, t.z, T::zero()]);
+        Motor(Pga::scalar(T::one()) - e0.geop(&t).scale(T::from_f64(0.5)))
+    }
+
+    /// The rigid transform x ↦ R x R̃ + t, rotating first and translating
+    /// afterwards.
+    pub fn new(rotor: &Rotor<T>, translation: &Vector<T>) -> Self {
+        Motor::from(*rotor).then(&Motor::translation(translation))
+    }
+
+    /// The rotor and translation of `Motor::new` that give this unit motor.
+    pub fn decompose(&self) -> (Rotor<T>, Vector<T>) {
+        let m = &self.0;
+        let rotor = Rotor::new(m.get(0), Bivector::new(m.get(0b011), m.get(0b110), -m.get(0b101)));
+        let translator = m.geop(&Motor::from(rotor).0.reverse());
+        let t = Vector::new(translator.get(0b1001), translator.get(0b1010), translator.get(0b1100));
+        (rotor, t * T::from_f64(2.0))
+    }
+
+    /// Rescales to a unit motor, M M̃ = 1, dividing by the square root of
+    /// M M̃ = s + p e1230. Since e1230 squares to zero that root is
+    /// √s + p / (2√s) e1230, and its inverse is 1/√s - p / (2s√s) e1230.
+    /// Renormalizing after long chains of compositions removes the drift
+    /// of rounding error.
+    pub fn normalized(&self) -> Motor<T> {
+        let norm = self.0.geop(&self.0.reverse());
+        let (s, p) = (norm.get(0), norm.get(0b1111));
+        let inv_sqrt = Pga::scalar(T::one() / s.sqrt()) + Pga::blade(0b1111, -p / (T::from_f64(2.0) * s * s.sqrt()));
+        Motor(self.0.geop(&inv_sqrt))
+    }
+
+    /// Screw interpolation from `self` at `t = 0` to `other` at `t = 1`,
+    /// rotating at a constant rate about the fixed screw axis of the
+    /// relative motion while translating at a constant rate along it. M and
+    /// -M are the same motion, so the relative motor is negated when its
+    /// scalar part is negative to take the shorter way round.
+    pub fn interpolate(&self, other: &Motor<T>, t: T) -> Motor<T> {
+        let mut relative = other.0.geop(&self.0.reverse());
+        if relative.get(0) < T::zero() {
+            relative = relative.scale(-T::one());
+        }
+        let (rotor, translation) = Motor(relative).decompose();
+        let log = rotor.log();
+        let partial = (log * t).exp();
+
+        // The axis passes through the point c in the plane of rotation with
+        // c - R c R̃ = t⊥. In the plane R c R̃ = c R̃², so c = t⊥ (1 - R̃²)⁻¹.
+        let along = translation.reject_from(&log);
+        let across = Multivector::from(translation.project_onto(&log));
+        let turn = Multivector::from(rotor.reverse().geop(&rotor.reverse()));
+        let screw = match (Multivector::from(Scalar { value: T::one() }) - turn).inverse() {
+            Some(inv) if log.mag() > T::epsilon().sqrt() => {
+                let c = across.geop(&inv).vector();
+                c - partial.rotate(&c) + along * t
+            }
+            _ => translation * t
+        };
+        self.then(&Motor::new(&partial, &screw))
+    }
+
+    /// The unit dual quaternion (q_r, q_d), q_r + ε q_d with ε² = 0, in the
+    /// [w, x, y, z] layout of `Rotor::to_quaternion`. The real part is the
+    /// rotation and the dual part is ½ t q_r for the translation t.
+    pub fn to_dual_quaternion(&self) -> ([T; 4], [T; 4]) {
+        let (rotor, t) = self.decompose();
+        let real = rotor.to_quaternion();
+        let half = T::from_f64(0.5);
+        let dual = quaternion_product([T::zero(), t.x * half, t.y * half, t.z * half], real);
+        (real, dual)
+    }
+
+    /// The motor of a unit dual quaternion, see `to_dual_quaternion`.
+    pub fn from_dual_quaternion(real: [T; 4], dual: [T; 4]) -> Self {
+        let conjugate = [real[0], -real[1], -real[2], -real[3]];
+        let t = quaternion_product(dual, conjugate);
+        let two = T::from_f64(2.0);
+        Motor::new(&Rotor::from_quaternion(real), &Vector::new(two * t[1], two * t[2], two * t[3]))
+    }
+
+    /// The homogeneous matrix [R t; 0 1] acting on column vectors.
+    pub fn to_matrix(&self) -> [[T; 4]; 4] {
+        let (rotor, t) = self.decompose();
+        let r = rotor.to_matrix();
+        let (zero, one) = (T::zero(), T::one());
+        [
+            [r[0][0], r[0][1], r[0][2], t.x],
+            [r[1][0], r[1][1], r[1][2], t.y],
+            [r[2][0], r[2][1], r[2][2], t.z],
+            [zero, zero, zero, one]
+        ]
+    }
+
+    /// The motor of a rigid homogeneous matrix [R t; 0 1]. The bottom row is
+    /// assumed to be [0 0 0 1] and is not read.
+    pub fn from_matrix(m: &[[T; 4]; 4]) -> Self {
+        let r = [
+            [m[0][0], m[0][1], m[0][2]],
+            [m[1][0], m[1][1], m[1][2]],
+            [m[2][0], m[2][1], m[2][2]]
+        ];
+        Motor::new(&Rotor::from_matrix(&r), &Vector::new(m[0][3], m[1][3], m[2][3]))
+    }
+
+    /// Moves a point given by its Euclidean coordinates, or `None` for the
+    /// zero motor, which sends every point to zero weight.
+    pub fn transform_point(&self, p: &Vector<T>) -> Option<Vector<T>> {
+        self.apply_point(&Point::from(p)).to_vector()
+    }
+
+    /// Rotates a direction, which translations leave unchanged.
+    pub fn transform_direction(&self, d: &Vector<T>) -> Vector<T> {
+        self.apply_point(&Point::direction(d)).to_direction()
+    }
+}
+
+/// The Hamilton product of quaternions in [w, x, y, z] layout.
+fn quaternion_product<T: Ring>(a: [T; 4], b: [T; 4]) -> [T; 4] {
+    [
+        a[0] * b[0] - a[1] * b[1] - a[2] * b[2] - a[3] * b[3],
+        a[0] * b[1] + a[1] * b[0] + a[2] * b[3] - a[3] * b[2],
+        a[0] * b[2] - a[1] * b[3] + a[2] * b[0] + a[3] * b[1],
+        a[0] * b[3] + a[1] * b[2] - a[2] * b[1] + a[3] * b[0]
+    ]
+}
+
+impl<T: Ring> Reverse for Motor<T> {
+    fn reverse(&self) -> Motor<T> {
+        Motor(self.0.reverse())
+    }
+}
+
+/// A rotation about the origin.
+impl<T: Ring> From<Rotor<T>> for Motor<T> {
+    fn from(r: Rotor<T>) -> Self {
+        let mut mv = Pga::scalar(r.scalar);
+        mv.set(0b011, r.bivector.e12);
+        mv.set(0b110, r.bivector.e23);
+        mv.set(0b101, -r.bivector.e31);
+        Motor(mv)
+    }
+}
+
+impl<T: Ring> Meet for Plane<T> {
+    type Output = Line<T>;
+
+    fn meet(&self, other: &Plane<T>) -> Line<T> {
+        Line(self.0.wedgep(&other.0))
+    }
+}
+
+impl<T: Ring> Meet<Plane<T>> for Line<T> {
+    type Output = Point<T>;
+
+    fn meet(&self, other: &Plane<T>) -> Point<T> {
+        Point(self.0.wedgep(&other.0))
+    }
+}
+
+impl<T: Ring> Meet<Line<T>> for Plane<T> {
+    type Output = Point<T>;
+
+    fn meet(&self, other: &Line<T>) -> Point<T> {
+        Point(self.0.wedgep(&other.0))
+    }
+}
+
+impl<T: Ring> Join for Point<T> {
+    type Output = Line<T>;
+
+    fn join(&self, other: &Point<T>) -> Line<T> {
+        Line(self.0.regressive(&other.0))
+    }
+}
+
+impl<T: Ring> Join<Point<T>> for Line<T> {
+    type Output = Plane<T>;
+
+    fn join(&self, other: &Point<T>) -> Plane<T> {
+        Plane(self.0.regressive(&other.0))
+    }
+}
+
+impl<T: Ring> Join<Line<T>> for Point<T> {
+    type Output = Plane<T>;
+
+    fn join(&self, other: &Line<T>) -> Plane<T> {
+        Plane(self.0.regressive(&other.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use approx::{assert_close, assert_close_within};
+    use clifford::Pga3;
+    use std::f64::consts::PI;
+
+    #[test]
+    fn test_point_from_vector() {
+        let v = Vector::new(1.0, -2.0, 3.0);
+
+        assert_close(v, Point::from(&v).to_vector().unwrap());
+        assert!(!Point::from(v).is_ideal(1e-12));
+        assert!(Point::direction(&v).is_ideal(1e-12));
+        assert_eq!(None, Point::direction(&v).to_vector());
+        assert_eq!(None, Motor(Pga3::zero()).transform_point(&v));
+
+        let mut nearly = Point::direction(&v);
+        nearly.0.set(E123, 1e-15);
+        assert!(nearly.is_ideal(1e-12));
+        assert!(!nearly.is_ideal(0.0));
+    }
+
+    #[test]
+    fn test_meet_planes() {
+        let x = Plane::new(1.0, 0.0, 0.0, -1.0);
+        let y = Plane::new(0.0, 1.0, 0.0, -2.0);
+        let z = Plane::new(0.0, 0.0, 1.0, -3.0);
+
+        assert_close(Vector::new(1.0, 2.0, 3.0), x.meet(&y).meet(&z).to_vector().unwrap());
+        assert_close(Vector::new(1.0, 2.0, 3.0), z.meet(&x.meet(&y)).to_vector().unwrap());
+    }
+
+    #[test]
+    fn test_single_precision() {
+        let motor = Motor::<f32>::new(&Rotor::from_axis_angle(&Vector::new(0.0, 0.0, 1.0), ::std::f32::consts::PI / 2.0), &Vector::new(0.0, 0.0, 5.0));
+        let p = motor.transform_point(&Vector::new(1.0, 0.0, 0.0)).unwrap();
+
+        assert_close_within(Vector::new(0.0, 1.0, 5.0), p, 1e-5);
+        assert!(Point::<f32>::direction(&p).is_ideal(1e-6));
+    }
+
+    #[test]
+    fn test_join_points() {
+        let a = Point::new(1.0, 0.0, 0.0);
+        let b = Point::new(0.0, 1.0, 0.0);
+        let c = Point::new(0.0, 0.0, 1.0);
+
+        let plane = Plane::from_points(&a, &b, &c);
+        let scale = plane.0.get(E0);
+        let expected = Plane::new(-scale, -scale, -scale, scale);
+
+        assert_eq!(expected, plane);
+        assert_eq!(Pga3::zero(), plane.0.wedgep(&a.0));
+    }
+
+    #[test]
+    fn test_line_meets_plane() {
+        let line = Line::from_points(&Point::new(0.0, 0.0, -1.0), &Point::new(2.0, 2.0, 1.0));
+        let plane = Plane::new(0.0, 0.0, 1.0, 0.0);
+
+        assert_close(Vector::new(1.0, 1.0, 0.0), line.meet(&plane).to_vector().unwrap());
+    }
+
+    #[test]
+    fn test_motor_translation() {
+        let motor = Motor::translation(&Vector::new(1.0, 2.0, 3.0));
+        let p = Point::new(1.0, 1.0, 1.0);
+
+        assert_close(Vector::new(2.0, 3.0, 4.0), motor.apply_point(&p).to_vector().unwrap());
+        assert_eq!(Point::direction(&Vector::new(1.0, 0.0, 0.0)),
+            motor.apply_point(&Point::direction(&Vector::new(1.0, 0.0, 0.0))));
+    }
+
+    #[test]
+    fn test_motor_rotation_then_translation() {
+        let rotation = Motor::from(Rotor::from_axis_angle(&Vector::new(0.0, 0.0, 1.0), PI / 2.0));
+        let translation = Motor::translation(&Vector::new(0.0, 0.0, 5.0));
+        let motor = rotation.then(&translation);
+
+        assert_close(Vector::new(0.0, 1.0, 5.0), motor.apply_point(&Point::new(1.0, 0.0, 0.0)).to_vector().unwrap());
+    }
+
+    #[test]
+    fn test_motor_moves_planes_and_lines() {
+        let motor = Motor::translation(&Vector::new(0.0, 0.0, 2.0));
+        let a = Point::new(0.0, 0.0, 0.0);
+        let b = Point::new(1.0, 0.0, 0.0);
+        let c = Point::new(0.0, 1.0, 0.0);
+
+        let plane = motor.apply_plane(&Plane::from_points(&a, &b, &c));
+        let line = motor.apply_line(&Line::from_points(&a, &b));
+
+        assert_eq!(Pga3::zero(), plane.0.wedgep(&motor.apply_point(&c).0));
+        assert_eq!(Pga3::zero(), line.0.wedgep(&motor.apply_point(&b).0));
+    }
+
+    fn assert_motor_eq(expected: &Motor, actual: &Motor) {
+        assert_close(expected.0.clone(), actual.0.clone());
+    }
+
+    fn screw() -> Motor {
+        Motor::new(&Rotor::from_axis_angle(&Vector::new(1.0, 2.0, 2.0), 0.9), &Vector::new(3.0, -1.0, 2.0))
+    }
+
+    #[test]
+    fn test_motor_new_and_decompose() {
+        let rotor = Rotor::from_axis_angle(&Vector::new(1.0, 2.0, 2.0), 0.9);
+        let t = Vector::new(3.0, -1.0, 2.0);
+        let motor = Motor::new(&rotor, &t);
+        let (r, translation) = motor.decompose();
+        let p = Vector::new(1.0, 0.5, -2.0);
+
+        assert_close(rotor.rotate(&p) + t, motor.transform_point(&p).unwrap());
+        assert_close(rotor.rotate(&p), motor.transform_direction(&p));
+        assert_close(t, translation);
+        assert!((Multivector::from(r) - Multivector::from(rotor)).mag() < 1e-12);
+    }
+
+    #[test]
+    fn test_motor_composition_stays_rigid() {
+        let step = screw();
+        let mut motor = Motor::identity();
+        for _ in 0..1000 {
+            motor = motor.then(&step);
+        }
+        let normalized = motor.normalized();
+        let norm = normalized.0.geop(&normalized.0.reverse());
+
+        assert!((norm.get(0) - 1.0).abs() < 1e-12);
+        assert!(norm.get(0b1111).abs() < 1e-12);
+        let (a, b) = (Vector::new(0.0, 0.0, 0.0), Vector::new(1.0, 1.0, 1.0));
+        assert!(((normalized.transform_point(&a).unwrap() - normalized.transform_point(&b).unwrap()).mag() - 3f64.sqrt()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_normalized_rescales() {
+        let motor = Motor(screw().0.scale(3.0));
+
+        assert_motor_eq(&screw(), &motor.normalized());
+    }
+
+    #[test]
+    fn test_dual_quaternion_round_trip() {
+        let motor = screw();
+        let (real, dual) = motor.to_dual_quaternion();
+
+        assert_motor_eq(&motor, &Motor::from_dual_quaternion(real, dual));
+
+        let (real, dual) = Motor::translation(&Vector::new(2.0, 0.0, 0.0)).to_dual_quaternion();
+        assert_eq!([1.0, 0.0, 0.0, 0.0], real);
+        assert_eq!([0.0, 1.0, 0.0, 0.0], dual);
+    }
+
+    #[test]
+    fn test_dual_quaternion_composes_like_motor() {
+        let a = screw();
+        let b = Motor::new(&Rotor::from_axis_angle(&Vector::new(0.0, 1.0, 0.0), -0.4), &Vector::new(0.0, 0.0, 1.0));
+        let ((ar, ad), (br, bd)) = (a.to_dual_quaternion(), b.to_dual_quaternion());
+        let real = quaternion_product(br, ar);
+        let dual = quaternion_product(br, ad);
+        let cross = quaternion_product(bd, ar);
+        let dual = [dual[0] + cross[0], dual[1] + cross[1], dual[2] + cross[2], dual[3] + cross[3]];
+
+        assert_motor_eq(&a.then(&b), &Motor::from_dual_quaternion(real, dual));
+    }
+
+    #[test]
+    fn test_matrix_round_trip() {
+        let motor = screw();
+        let m = motor.to_matrix();
+        let p = Vector::new(1.0, 0.5, -2.0);
+        let mp = Vector::new(
+            m[0][0] * p.x + m[0][1] * p.y + m[0][2] * p.z + m[0][3],
+            m[1][0] * p.x + m[1][1] * p.y + m[1][2] * p.z + m[1][3],
+            m[2][0] * p.x + m[2][1] * p.y + m[2][2] * p.z + m[2][3]
+        );
+
+        assert_close(motor.transform_point(&p).unwrap(), mp);
+        assert_motor_eq(&motor, &Motor::from_matrix(&m));
+    }
+
+    #[test]
+    fn test_interpolate_endpoints() {
+        let start = Motor::translation(&Vector::new(1.0, 0.0, 0.0));
+        let end = screw();
+
+        assert_motor_eq(&start, &start.interpolate(&end, 0.0));
+        assert_motor_eq(&end, &start.interpolate(&end, 1.0));
+    }
+
+    #[test]
+    fn test_interpolate_follows_screw() {
+        let start = Motor::translation(&Vector::new(1.0, 0.0, 0.0));
+        let end = start.then(&screw());
+        let half = start.interpolate(&end, 0.5);
+        let relative = Motor(half.0.geop(&start.0.reverse()));
+
+        assert_motor_eq(&end, &half.then(&relative));
+
+        let quarter_turn = Motor::from(Rotor::from_axis_angle(&Vector::new(0.0, 0.0, 1.0), PI / 2.0));
+        let about_point = Motor::translation(&Vector::new(-1.0, 0.0, 0.0))
+            .then(&quarter_turn)
+            .then(&Motor::translation(&Vector::new(1.0, 0.0, 0.0)));
+        let midway = Motor::identity().interpolate(&about_point, 0.5);
+        let expected = Vector::new(1.0 - (PI / 4.0).cos(), -(PI / 4.0).sin(), 0.0);
+        assert_close(expected, midway.transform_point(&Vector::new(0.0, 0.0, 0.0)).unwrap());
+    }
+
+    #[test]
+    fn test_interpolate_takes_shorter_way() {
+        let z = Vector::new(0.0, 0.0, 1.0);
+        let start = Motor::from(Rotor::from_axis_angle(&z, 170.0f64.to_radians()));
+        let end = Motor::from(Rotor::from_axis_angle(&z, -170.0f64.to_radians()));
+        let half = start.interpolate(&end, 0.5);
+
+        assert_close(Vector::new(-1.0, 0.0, 0.0), half.transform_direction(&Vector::new(1.0, 0.0, 0.0)));
+    }
+
+    #[test]
+    fn test_interpolate_translation() {
+        let end = Motor::translation(&Vector::new(2.0, 4.0, 0.0));
+
+        assert_close(Vector::new(0.5, 1.0, 0.0), Motor::identity().interpolate(&end, 0.25).transform_point(&Vector::new(0.0, 0.0, 0.0)).unwrap());
+    }
+}
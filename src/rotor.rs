@@ -0,0 +1,531 @@
+use {Bivector, Conjugate, Dual, GeometricProduct, GradeInvolution, InnerProduct, Inverse, Magnitude, Multivector, RealField, Reverse, Ring, Scalar, Vector, WedgeProduct};
+
+/// The twelve intrinsic rotation sequences: the six Tait-Bryan sequences
+/// about three distinct axes and the six proper Euler sequences repeating
+/// the first axis. Angles (a, b, c) for `Xyz` rotate by a about x, then by b
+/// about the rotated y and by c about the twice rotated z, which is the
+/// matrix product Rx(a) Ry(b) Rz(c). The extrinsic sequence about fixed
+/// axes is the intrinsic one with both the axes and the angles reversed.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum EulerSequence {
+    Xyz, Xzy, Yxz, Yzx, Zxy, Zyx,
+    Xyx, Xzx, Yxy, Yzy, Zxz, Zyz
+}
+
+impl EulerSequence {
+    pub const ALL: [EulerSequence; 12] = [
+        EulerSequence::Xyz, EulerSequence::Xzy, EulerSequence::Yxz, EulerSequence::Yzx,
+        EulerSequence::Zxy, EulerSequence::Zyx, EulerSequence::Xyx, EulerSequence::Xzx,
+        EulerSequence::Yxy, EulerSequence::Yzy, EulerSequence::Zxz, EulerSequence::Zyz
+    ];
+
+    /// The axes in order, 0 for x, 1 for y and 2 for z.
+    pub fn axes(&self) -> [usize; 3] {
+        match *self {
+            EulerSequence::Xyz => [0, 1, 2],
+            EulerSequence::Xzy => [0, 2, 1],
+            EulerSequence::Yxz => [1, 0, 2],
+            EulerSequence::Yzx => [1, 2, 0],
+            EulerSequence::Zxy => [2, 0, 1],
+            EulerSequence::Zyx => [2, 1, 0],
+            EulerSequence::Xyx => [0, 1, 0],
+            EulerSequence::Xzx => [0, 2, 0],
+            EulerSequence::Yxy => [1, 0, 1],
+            EulerSequence::Yzy => [1, 2, 1],
+            EulerSequence::Zxz => [2, 0, 2],
+            EulerSequence::Zyz => [2, 1, 2]
+        }
+    }
+}
+
+/// An even-grade element of Cl(3,0), a scalar plus a bivector. Unit rotors
+/// rotate vectors through the sandwich product R v R̃.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Rotor<T = f64> {
+    pub scalar: T,
+    pub bivector: Bivector<T>
+}
+
+impl<T: Ring> Rotor<T> {
+    pub fn new(scalar: T, bivector: Bivector<T>) -> Self {
+        Rotor {
+            scalar,
+            bivector
+        }
+    }
+
+    pub fn identity() -> Self {
+        Rotor::new(T::one(), Bivector::new(T::zero(), T::zero(), T::zero()))
+    }
+
+    /// The rotor applying `self` first and `next` afterwards, next * self.
+    pub fn then(&self, next: &Rotor<T>) -> Rotor<T> {
+        next.geop(self)
+    }
+
+    pub fn rotate(&self, v: &Vector<T>) -> Vector<T> {
+        Multivector::from(*self)
+            .geop(&Multivector::from(v))
+            .geop(&Multivector::from(self.reverse()))
+            .vector()
+    }
+}
+
+impl<T: RealField> Rotor<T> {
+    /// Rotation by `angle` radians in the oriented `plane`, turning e1
+    /// towards e2 for the plane e12. The rotor is cos(θ/2) - B̂ sin(θ/2).
+    /// A zero plane has no orientation and gives the identity.
+    pub fn from_plane_angle(plane: &Bivector<T>, angle: T) -> Self {
+        let mag = plane.mag();
+        if mag.is_zero() {
+            return Rotor::identity();
+        }
+        let (sin, cos) = (angle / T::from_f64(2.0)).sin_cos();
+        Rotor::new(cos, Bivector::new(
+            -plane.e12 / mag * sin,
+            -plane.e23 / mag * sin,
+            -plane.e31 / mag * sin
+        ))
+    }
+
+    /// Right-handed rotation by `angle` radians about `axis`. The plane of
+    /// rotation is the undual of the axis, a I, so a zero axis gives the
+    /// identity.
+    pub fn from_axis_angle(axis: &Vector<T>, angle: T) -> Self {
+        Rotor::from_plane_angle(&axis.undual(), angle)
+    }
+
+    /// The smallest rotation taking the direction of `from` onto the
+    /// direction of `to`, (1 + b̂ â) / |1 + b̂ â|. Opposite vectors are
+    /// turned by π in an arbitrary plane containing `from`, and a zero
+    /// vector has no direction and gives the identity.
+    pub fn from_vectors(from: &Vector<T>, to: &Vector<T>) -> Self {
+        let (from_mag, to_mag) = (from.mag(), to.mag());
+        if from_mag.is_zero() || to_mag.is_zero() {
+            return Rotor::identity();
+        }
+        let a = Vector::new(from.x / from_mag, from.y / from_mag, from.z / from_mag);
+        let b = Vector::new(to.x / to_mag, to.y / to_mag, to.z / to_mag);
+
+        let scalar = T::one() + b.innerp(&a).value;
+        if scalar <= T::epsilon() {
+            let (zero, one) = (T::zero(), T::one());
+            let helper = if a.x.abs() < T::from_f64(0.9) { Vector::new(one, zero, zero) } else { Vector::new(zero, one, zero) };
+            return Rotor::from_plane_angle(&a.wedgep(&helper), T::pi());
+        }
+
+        let plane = b.wedgep(&a);
+        let rotor = Rotor::new(scalar, plane);
+        let mag = rotor.mag();
+        Rotor::new(scalar / mag, Bivector::new(plane.e12 / mag, plane.e23 / mag, plane.e31 / mag))
+    }
+
+    /// The unit quaternion [w, x, y, z] = w + xi + yj + zk rotating vectors
+    /// the same way through q v q*. The right-handed rotation by θ about a
+    /// unit axis n is cos(θ/2) + sin(θ/2) n on both sides, and since the
+    /// rotor's plane is n I, the quaternion units map to i = -e23, j = -e31
+    /// and k = -e12, which satisfy ij = k.
+    pub fn to_quaternion(&self) -> [T; 4] {
+        [self.scalar, -self.bivector.e23, -self.bivector.e31, -self.bivector.e12]
+    }
+
+    /// The rotor of the quaternion [w, x, y, z], see `to_quaternion`.
+    pub fn from_quaternion(q: [T; 4]) -> Self {
+        Rotor::new(q[0], Bivector::new(-q[3], -q[1], -q[2]))
+    }
+
+    /// The rotation matrix M of this unit rotor, acting on column vectors
+    /// y = M x, so column j is the image of the jth basis vector.
+    pub fn to_matrix(&self) -> [[T; 3]; 3] {
+        let [w, x, y, z] = self.to_quaternion();
+        let two = T::from_f64(2.0);
+        let one = T::one();
+        [
+            [one - two * (y * y + z * z), two * (x * y - w * z), two * (x * z + w * y)],
+            [two * (x * y + w * z), one - two * (x * x + z * z), two * (y * z - w * x)],
+            [two * (x * z - w * y), two * (y * z + w * x), one - two * (x * x + y * y)]
+        ]
+    }
+
+    /// The unit rotor of the rotation matrix M, see `to_matrix`. Shepperd's
+    /// method divides by the largest of the four quaternion components,
+    /// found from the trace and the diagonal, so it stays accurate near
+    /// half turns where the trace alone loses precision.
+    pub fn from_matrix(m: &[[T; 3]; 3]) -> Self {
+        let (one, four) = (T::one(), T::from_f64(4.0));
+        let trace = m[0][0] + m[1][1] + m[2][2];
+        let q = if trace >= m[0][0] && trace >= m[1][1] && trace >= m[2][2] {
+            let w = (one + trace).sqrt() / T::from_f64(2.0);
+            [w, (m[2][1] - m[1][2]) / (four * w), (m[0][2] - m[2][0]) / (four * w), (m[1][0] - m[0][1]) / (four * w)]
+        } else if m[0][0] >= m[1][1] && m[0][0] >= m[2][2] {
+            let x = (one + m[0][0] - m[1][1] - m[2][2]).sqrt() / T::from_f64(2.0);
+            [(m[2][1] - m[1][2]) / (four * x), x, (m[0][1] + m[1][0]) / (four * x), (m[0][2] + m[2][0]) / (four * x)]
+        } else if m[1][1] >= m[2][2] {
+            let y = (one - m[0][0] + m[1][1] - m[2][2]).sqrt() / T::from_f64(2.0);
+            [(m[0][2] - m[2][0]) / (four * y), (m[0][1] + m[1][0]) / (four * y), y, (m[1][2] + m[2][1]) / (four * y)]
+        } else {
+            let z = (one - m[0][0] - m[1][1] + m[2][2]).sqrt() / T::from_f64(2.0);
+            [(m[1][0] - m[0][1]) / (four * z), (m[0][2] + m[2][0]) / (four * z), (m[1][2] + m[2][1]) / (four * z), z]
+        };
+        Rotor::from_quaternion(q)
+    }
+
+    /// The rotor of the intrinsic rotation sequence `seq` by `angles`.
+    pub fn from_euler(seq: EulerSequence, angles: [T; 3]) -> Self {
+        let basis = [
+            Vector::new(T::one(), T::zero(), T::zero()),
+            Vector::new(T::zero(), T::one(), T::zero()),
+            Vector::new(T::zero(), T::zero(), T::one())
+        ];
+        let axes = seq.axes();
+        let first = Rotor::from_axis_angle(&basis[axes[0]], angles[0]);
+        let second = Rotor::from_axis_angle(&basis[axes[1]], angles[1]);
+        let third = Rotor::from_axis_angle(&basis[axes[2]], angles[2]);
+        first.geop(&second).geop(&third)
+    }
+
+    /// Angles of the intrinsic sequence `seq` reproducing this unit rotor,
+    /// following Bernardes and Viollet's direct method on the quaternion.
+    /// The first and last angles lie in (-π, π]; the middle one lies in
+    /// [0, π] for proper Euler sequences and [-π/2, π/2] for Tait-Bryan
+    /// ones. At gimbal lock only the sum or difference of the outer angles
+    /// is determined, and the first angle is set to zero.
+    pub fn to_euler(&self, seq: EulerSequence) -> [T; 3] {
+        let q = self.to_quaternion();
+        let axes = seq.axes();
+        // The method works on the extrinsic sequence, the reversed axes.
+        let (i, j) = (axes[2], axes[1]);
+        let proper = axes[0] == axes[2];
+        let k = if proper { 3 - i - j } else { axes[0] };
+        let sign = T::from_f64(((i as i32 - j as i32) * (j as i32 - k as i32) * (k as i32 - i as i32) / 2) as f64);
+        let (w, qi, qj, qk) = (q[0], q[i + 1], q[j + 1], q[k + 1] * sign);
+        let (a, b, c, d) = if proper {
+            (w, qi, qj, qk)
+        } else {
+            (w - qj, qi + qk, qj + w, qk - qi)
+        };
+
+        let two = T::from_f64(2.0);
+        let mut middle = two * (c * c + d * d).sqrt().atan2((a * a + b * b).sqrt());
+        let half_sum = b.atan2(a);
+        let half_diff = d.atan2(c);
+        let tolerance = T::epsilon().sqrt();
+        let (mut first, mut last) = if middle.abs() <= tolerance {
+            (two * half_sum, T::zero())
+        } else if (middle - T::pi()).abs() <= tolerance {
+            (-two * half_diff, T::zero())
+        } else {
+            (half_sum - half_diff, half_sum + half_diff)
+        };
+        if !proper {
+            last = last * sign;
+            middle = middle - T::pi() / two;
+        }
+        // Undo the reversal: the extrinsic angles come out in reverse order.
+        ::std::mem::swap(&mut first, &mut last);
+        [wrap_angle(first), middle, wrap_angle(last)]
+    }
+
+    /// The bivector B with exp(B) equal to this unit rotor, so a rotation by
+    /// θ in the plane B̂ has logarithm -θ/2 B̂. The identity gives zero, and a
+    /// rotor of -1, whose plane is undetermined, gives π e12.
+    pub fn log(&self) -> Bivector<T> {
+        let mag = self.bivector.mag();
+        if mag.is_zero() && self.scalar < T::zero() {
+            return Bivector::new(T::pi(), T::zero(), T::zero());
+        }
+        if mag <= T::epsilon() && self.scalar > T::zero() {
+            return self.bivector / self.scalar;
+        }
+        self.bivector * (mag.atan2(self.scalar) / mag)
+    }
+}
+
+impl<T: RealField> Bivector<T> {
+    /// The rotor exp(B) = cos|B| + B̂ sin|B|, a rotation by -2|B| in the
+    /// plane of B. Since B² = -|B|² in Cl(3,0) the series closes on the
+    /// trigonometric functions.
+    pub fn exp(&self) -> Rotor<T> {
+        let mag = self.mag();
+        if mag <= T::epsilon() {
+            return Rotor::new(mag.cos(), *self);
+        }
+        let (sin, cos) = mag.sin_cos();
+        Rotor::new(cos, *self * (sin / mag))
+    }
+}
+
+/// The angle equal to `angle` modulo 2π in (-π, π].
+fn wrap_angle<T: RealField>(angle: T) -> T {
+    let turn = T::from_f64(2.0) * T::pi();
+    if angle > T::pi() {
+        angle - turn
+    } else if angle <= -T::pi() {
+        angle + turn
+    } else {
+        angle
+    }
+}
+
+impl<T: Ring> Reverse for Rotor<T> {
+    fn reverse(&self) -> Self {
+        Rotor::new(self.scalar, -self.bivector)
+    }
+}
+
+/// Rotors are even, so grade involution leaves them unchanged.
+impl<T: Ring> GradeInvolution for Rotor<T> {
+    fn involute(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Ring> Conjugate for Rotor<T> {
+    fn conjugate(&self) -> Self {
+        self.reverse()
+    }
+}
+
+impl<T: RealField> Magnitude<T> for Rotor<T> {
+    fn mag(&self) -> T {
+        let b = self.bivector.mag();
+        (self.scalar * self.scalar + b * b).sqrt()
+    }
+}
+
+/// R̃ / (R R̃), which is just the reverse for a unit rotor.
+impl<T: RealField> Inverse for Rotor<T> {
+    fn inverse(&self) -> Option<Self> {
+        let norm = self.scalar * self.scalar + self.bivector.e12 * self.bivector.e12
+            + self.bivector.e23 * self.bivector.e23 + self.bivector.e31 * self.bivector.e31;
+        if norm.is_zero() {
+            return None;
+        }
+        let rev = self.reverse();
+        Some(Rotor::new(rev.scalar / norm, rev.bivector / norm))
+    }
+}
+
+impl<T: Ring> GeometricProduct for Rotor<T> {
+    type Output = Rotor<T>;
+
+    fn geop(&self, other: &Rotor<T>) -> Rotor<T> {
+        let mv = Multivector::from(*self).geop(&Multivector::from(*other));
+        Rotor::new(mv.scalar, mv.bivector())
+    }
+}
+
+impl<T: Ring> From<Rotor<T>> for Multivector<T> {
+    fn from(r: Rotor<T>) -> Self {
+        Multivector::from(Scalar { value: r.scalar }) + Multivector::from(r.bivector)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use approx::assert_close;
+    use std::f64::consts::PI;
+
+    #[test]
+    fn test_rotate_plane_angle() {
+        let plane = Bivector::new(1.0, 0.0, 0.0);
+        let rotor = Rotor::from_plane_angle(&plane, PI / 2.0);
+
+        assert_close(Vector::new(0.0, 1.0, 0.0), rotor.rotate(&Vector::new(1.0, 0.0, 0.0)));
+        assert_close(Vector::new(0.0, 0.0, 1.0), rotor.rotate(&Vector::new(0.0, 0.0, 1.0)));
+    }
+
+    #[test]
+    fn test_rotate_axis_angle() {
+        let rotor = Rotor::from_axis_angle(&Vector::new(1.0, 0.0, 0.0), PI / 2.0);
+
+        assert_close(Vector::new(0.0, 0.0, 1.0), rotor.rotate(&Vector::new(0.0, 1.0, 0.0)));
+    }
+
+    #[test]
+    fn test_rotor_from_vectors() {
+        let from = Vector::new(1.0, 2.0, 2.0);
+        let to = Vector::new(0.0, 3.0, 0.0);
+
+        let rotor = Rotor::from_vectors(&from, &to);
+
+        assert_close(Vector::new(0.0, 3.0, 0.0), rotor.rotate(&from));
+        assert!((rotor.mag() - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_rotor_from_opposite_vectors() {
+        let from = Vector::new(1.0, 0.0, 0.0);
+        let to = Vector::new(-2.0, 0.0, 0.0);
+
+        let rotor = Rotor::from_vectors(&from, &to);
+
+        assert_close(Vector::new(-1.0, 0.0, 0.0), rotor.rotate(&from));
+
+        let up = Vector::new(0.0, 0.0, 1.0);
+        let flip = Rotor::from_vectors(&up, &-up);
+        assert_close(-up, flip.rotate(&up));
+        assert!((flip.mag() - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_degenerate_inputs_give_identity() {
+        let zero = Vector::new(0.0, 0.0, 0.0);
+        let v = Vector::new(1.0, 2.0, 3.0);
+
+        assert_eq!(Rotor::identity(), Rotor::from_plane_angle(&Bivector::default(), 0.5));
+        assert_eq!(Rotor::identity(), Rotor::from_axis_angle(&zero, 0.5));
+        assert_eq!(Rotor::identity(), Rotor::from_vectors(&zero, &v));
+        assert_eq!(Rotor::identity(), Rotor::from_vectors(&v, &zero));
+    }
+
+    #[test]
+    fn test_rotor_then() {
+        let first = Rotor::from_axis_angle(&Vector::new(0.0, 0.0, 1.0), PI / 2.0);
+        let second = Rotor::from_axis_angle(&Vector::new(1.0, 0.0, 0.0), PI / 2.0);
+        let v = Vector::new(1.0, 0.0, 0.0);
+
+        let composed = first.then(&second);
+
+        assert_close(second.rotate(&first.rotate(&v)), composed.rotate(&v));
+        assert_close(Vector::new(0.0, 0.0, 1.0), composed.rotate(&v));
+    }
+
+    #[test]
+    fn test_exp_matches_plane_angle() {
+        let plane = Bivector::new(1.0, 2.0, -2.0);
+        let rotor = Rotor::from_plane_angle(&plane, 0.8);
+        let generator = plane * (-0.4 / plane.mag());
+        let v = Vector::new(3.0, -1.0, 2.0);
+
+        assert_close(rotor.rotate(&v), generator.exp().rotate(&v));
+        assert!((rotor.scalar - generator.exp().scalar).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_log_inverts_exp() {
+        let generator = Bivector::new(0.3, -0.2, 0.5);
+        let log = generator.exp().log();
+
+        assert!((log - generator).mag() < 1e-12);
+        assert_eq!(Bivector::default(), Rotor::<f64>::identity().log());
+        assert_eq!(Rotor::identity(), Bivector::<f64>::default().exp());
+    }
+
+    #[test]
+    fn test_log_at_half_turn() {
+        let half_turn = Rotor::from_axis_angle(&Vector::new(0.0, 1.0, 0.0), PI);
+        let log = half_turn.log();
+
+        assert!((log.mag() - PI / 2.0).abs() < 1e-12);
+        assert_close(half_turn.rotate(&Vector::new(1.0, 0.0, 0.0)), log.exp().rotate(&Vector::new(1.0, 0.0, 0.0)));
+
+        let minus_one = Rotor::new(-1.0, Bivector::default());
+        assert!((minus_one.log().mag() - PI).abs() < 1e-12);
+        assert_eq!(minus_one.scalar, minus_one.log().exp().scalar);
+    }
+
+    fn assert_same_rotation(expected: Rotor, actual: Rotor) {
+        let basis = [Vector::new(1.0, 0.0, 0.0), Vector::new(0.0, 1.0, 0.0), Vector::new(0.0, 0.0, 1.0)];
+        for e in basis.iter() {
+            assert_close(expected.rotate(e), actual.rotate(e));
+        }
+    }
+
+    #[test]
+    fn test_quaternion_round_trip() {
+        let rotor = Rotor::from_axis_angle(&Vector::new(1.0, 2.0, 2.0), 0.9);
+
+        assert_eq!(rotor, Rotor::from_quaternion(rotor.to_quaternion()));
+    }
+
+    #[test]
+    fn test_quaternion_handedness() {
+        let quarter = Rotor::from_axis_angle(&Vector::new(0.0, 0.0, 1.0), PI / 2.0);
+        let q = quarter.to_quaternion();
+        let half = (PI / 4.0).sin();
+
+        assert!((q[0] - half).abs() < 1e-12 && (q[3] - half).abs() < 1e-12);
+        assert_eq!((0.0, 0.0), (q[1], q[2]));
+
+        let i = Rotor::from_quaternion([0.0, 1.0, 0.0, 0.0]);
+        let j = Rotor::from_quaternion([0.0, 0.0, 1.0, 0.0]);
+        assert_eq!([0.0, 0.0, 0.0, 1.0], i.geop(&j).to_quaternion());
+    }
+
+    #[test]
+    fn test_matrix_round_trip() {
+        let rotor = Rotor::from_axis_angle(&Vector::new(1.0, 2.0, 2.0), 0.9);
+        let m = rotor.to_matrix();
+        let v = Vector::new(3.0, -1.0, 2.0);
+        let mv = Vector::new(
+            m[0][0] * v.x + m[0][1] * v.y + m[0][2] * v.z,
+            m[1][0] * v.x + m[1][1] * v.y + m[1][2] * v.z,
+            m[2][0] * v.x + m[2][1] * v.y + m[2][2] * v.z
+        );
+
+        assert_close(rotor.rotate(&v), mv);
+        assert_same_rotation(rotor, Rotor::from_matrix(&m));
+    }
+
+    #[test]
+    fn test_matrix_near_half_turns() {
+        for axis in [Vector::new(1.0, 0.0, 0.0), Vector::new(0.0, 1.0, 0.0), Vector::new(0.0, 0.0, 1.0), Vector::new(1.0, -1.0, 2.0)].iter() {
+            let rotor = Rotor::from_axis_angle(axis, PI - 1e-9);
+            let recovered = Rotor::from_matrix(&rotor.to_matrix());
+
+            assert_same_rotation(rotor, recovered);
+            assert!((recovered.mag() - 1.0).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_euler_round_trip() {
+        for seq in EulerSequence::ALL.iter() {
+            let angles = [0.3, 0.7, -1.1];
+            let rotor = Rotor::from_euler(*seq, angles);
+            let recovered = rotor.to_euler(*seq);
+
+            for n in 0..3 {
+                assert!((angles[n] - recovered[n]).abs() < 1e-12, "{:?}: {:?} != {:?}", seq, angles, recovered);
+            }
+        }
+    }
+
+    #[test]
+    fn test_euler_intrinsic_order() {
+        let rotor = Rotor::from_euler(EulerSequence::Zyx, [PI / 2.0, 0.0, PI / 2.0]);
+        let yaw = Rotor::from_axis_angle(&Vector::new(0.0, 0.0, 1.0), PI / 2.0);
+        let roll = Rotor::from_axis_angle(&yaw.rotate(&Vector::new(1.0, 0.0, 0.0)), PI / 2.0);
+
+        assert_same_rotation(yaw.then(&roll), rotor);
+    }
+
+    #[test]
+    fn test_euler_gimbal_lock() {
+        for seq in EulerSequence::ALL.iter() {
+            let axes = seq.axes();
+            let locked = if axes[0] == axes[2] { [0.0, PI] } else { [PI / 2.0, -PI / 2.0] };
+            for middle in locked.iter() {
+                let rotor = Rotor::from_euler(*seq, [0.4, *middle, 0.9]);
+                let recovered = Rotor::from_euler(*seq, rotor.to_euler(*seq));
+
+                assert_same_rotation(rotor, recovered);
+            }
+        }
+    }
+
+    #[test]
+    fn test_rotor_inverse() {
+        let rotor = Rotor::from_axis_angle(&Vector::new(1.0, 1.0, 1.0), 0.7);
+        let v = Vector::new(3.0, -1.0, 2.0);
+
+        assert_close(v, rotor.inverse().unwrap().rotate(&rotor.rotate(&v)));
+        let identity = rotor.then(&rotor.inverse().unwrap());
+        assert!((identity.scalar - 1.0).abs() < 1e-12);
+        assert!(identity.bivector.mag() < 1e-12);
+        assert_eq!(None, Rotor::new(0.0, Bivector::default()).inverse());
+    }
+}
@@ -0,0 +1,309 @@
+//! Sparse multivectors of Cl(p,q,r), storing only the non-zero
+//! coefficients keyed by basis-blade bitmask.
+//!
+//! Blades are numbered as in `clifford`, and products multiply every pair of
+//! stored terms using `clifford::reordering_sign` and the metric, so the cost
+//! depends on the number of terms rather than on 2ⁿ. The dense types remain
+//! the fast path for small algebras; convert with `From` when a generic
+//! algorithm wants the sparse form.
+
+use std::collections::BTreeMap;
+use std::ops::{Add, Mul, Neg, Sub};
+
+use clifford;
+use {Conjugate, GeometricProduct, GradeInvolution, InnerProduct, Reverse, Ring, WedgeProduct};
+
+/// A multivector of Cl(P,Q,R) holding its non-zero terms in blade order.
+#[derive(Clone, PartialEq, Debug)]
+pub struct SparseMultivector<const P: usize, const Q: usize, const R: usize, T = f64> {
+    terms: BTreeMap<usize, T>
+}
+
+impl<const P: usize, const Q: usize, const R: usize, T: Ring> SparseMultivector<P, Q, R, T> {
+    pub const DIM: usize = P + Q + R;
+
+    pub fn zero() -> Self {
+        SparseMultivector { terms: BTreeMap::new() }
+    }
+
+    pub fn scalar(value: T) -> Self {
+        Self::blade(0, value)
+    }
+
+    /// `value` times the basis blade with the given bitmask.
+    pub fn blade(mask: usize, value: T) -> Self {
+        let mut mv = Self::zero();
+        mv.set(mask, value);
+        mv
+    }
+
+    /// The `i`th basis vector, counting from zero.
+    pub fn basis_vector(i: usize) -> Self {
+        assert!(i < Self::DIM, "basis vector {} outside an algebra of dimension {}", i, Self::DIM);
+        Self::blade(1 << i, T::one())
+    }
+
+    pub fn get(&self, mask: usize) -> T {
+        self.terms.get(&mask).cloned().unwrap_or_else(T::zero)
+    }
+
+    /// Sets a coefficient, dropping the term when `value` is zero.
+    pub fn set(&mut self, mask: usize, value: T) {
+        assert!(mask.checked_shr(Self::DIM as u32).unwrap_or(0) == 0,
+            "blade mask {:#b} outside an algebra of dimension {}", mask, Self::DIM);
+        if value.is_zero() {
+            self.terms.remove(&mask);
+        } else {
+            self.terms.insert(mask, value);
+        }
+    }
+
+    /// The stored (mask, coefficient) pairs in increasing mask order.
+    pub fn terms(&self) -> impl Iterator<Item = (usize, T)> + '_ {
+        self.terms.iter().map(|(&mask, &c)| (mask, c))
+    }
+
+    /// The number of stored terms.
+    pub fn len(&self) -> usize {
+        self.terms.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    pub fn scale(&self, factor: T) -> Self {
+        let mut mv = Self::zero();
+        for (mask, c) in self.terms() {
+            mv.set(mask, c * factor);
+        }
+        mv
+    }
+
+    /// Negates the grade-k terms for which `flip(k)` holds.
+    fn negate_grades<F>(&self, flip: F) -> Self
+        where F: Fn(u32) -> bool
+    {
+        let mut mv = self.clone();
+        for (mask, c) in mv.terms.iter_mut() {
+            if flip(mask.count_ones()) {
+                *c = -*c;
+            }
+        }
+        mv
+    }
+
+    /// Sums the blade products over all pairs of terms whose grades
+    /// satisfy `keep`, with signs from `clifford::Multivector::basis_product`.
+    fn product<F>(&self, other: &Self, keep: F) -> Self
+        where F: Fn(u32, u32, u32) -> bool
+    {
+        let mut mv = Self::zero();
+        for (a, ca) in self.terms() {
+            for (b, cb) in other.terms() {
+                let (sign, mask) = clifford::Multivector::<P, Q, R, T>::basis_product(a, b);
+                if sign != 0.0 && keep(a.count_ones(), b.count_ones(), mask.count_ones()) {
+                    let sum = mv.get(mask) + (ca * cb).signed(sign);
+                    mv.set(mask, sum);
+                }
+            }
+        }
+        mv
+    }
+}
+
+impl<const P: usize, const Q: usize, const R: usize, T: Ring> GeometricProduct for SparseMultivector<P, Q, R, T> {
+    type Output = SparseMultivector<P, Q, R, T>;
+
+    fn geop(&self, other: &Self) -> Self {
+        self.product(other, |_, _, _| true)
+    }
+}
+
+impl<const P: usize, const Q: usize, const R: usize, T: Ring> WedgeProduct for SparseMultivector<P, Q, R, T> {
+    type Output = SparseMultivector<P, Q, R, T>;
+
+    fn wedgep(&self, other: &Self) -> Self {
+        self.product(other, |ga, gb, g| g == ga + gb)
+    }
+}
+
+/// The left contraction A ⌋ B.
+impl<const P: usize, const Q: usize, const R: usize, T: Ring> InnerProduct for SparseMultivector<P, Q, R, T> {
+    type Output = SparseMultivector<P, Q, R, T>;
+
+    fn innerp(&self, other: &Self) -> Self {
+        self.product(other, |ga, gb, g| gb >= ga && g == gb - ga)
+    }
+}
+
+impl<const P: usize, const Q: usize, const R: usize, T: Ring> Reverse for SparseMultivector<P, Q, R, T> {
+    fn reverse(&self) -> Self {
+        self.negate_grades(|k| k % 4 == 2 || k % 4 == 3)
+    }
+}
+
+impl<const P: usize, const Q: usize, const R: usize, T: Ring> GradeInvolution for SparseMultivector<P, Q, R, T> {
+    fn involute(&self) -> Self {
+        self.negate_grades(|k| k % 2 == 1)
+    }
+}
+
+impl<const P: usize, const Q: usize, const R: usize, T: Ring> Conjugate for SparseMultivector<P, Q, R, T> {
+    fn conjugate(&self) -> Self {
+        self.negate_grades(|k| k % 4 == 1 || k % 4 == 2)
+    }
+}
+
+impl<const P: usize, const Q: usize, const R: usize, T: Ring> Add for SparseMultivector<P, Q, R, T> {
+    type Output = SparseMultivector<P, Q, R, T>;
+
+    fn add(self, other: Self) -> Self {
+        let mut mv = self;
+        for (mask, c) in other.terms() {
+            let sum = mv.get(mask) + c;
+            mv.set(mask, sum);
+        }
+        mv
+    }
+}
+
+impl<const P: usize, const Q: usize, const R: usize, T: Ring> Sub for SparseMultivector<P, Q, R, T> {
+    type Output = SparseMultivector<P, Q, R, T>;
+
+    fn sub(self, other: Self) -> Self {
+        self + -other
+    }
+}
+
+impl<const P: usize, const Q: usize, const R: usize, T: Ring> Neg for SparseMultivector<P, Q, R, T> {
+    type Output = SparseMultivector<P, Q, R, T>;
+
+    fn neg(self) -> Self {
+        self.negate_grades(|_| true)
+    }
+}
+
+impl<const P: usize, const Q: usize, const R: usize, T: Ring> Mul for SparseMultivector<P, Q, R, T> {
+    type Output = SparseMultivector<P, Q, R, T>;
+
+    fn mul(self, other: Self) -> Self {
+        self.geop(&other)
+    }
+}
+
+impl<const P: usize, const Q: usize, const R: usize, T: Ring> Mul<T> for SparseMultivector<P, Q, R, T> {
+    type Output = SparseMultivector<P, Q, R, T>;
+
+    fn mul(self, k: T) -> Self {
+        self.scale(k)
+    }
+}
+
+impl<const P: usize, const Q: usize, const R: usize, T: Ring> From<clifford::Multivector<P, Q, R, T>>
+    for SparseMultivector<P, Q, R, T>
+{
+    fn from(m: clifford::Multivector<P, Q, R, T>) -> Self {
+        let mut mv = Self::zero();
+        for (mask, c) in m.coeffs().iter().enumerate() {
+            mv.set(mask, *c);
+        }
+        mv
+    }
+}
+
+impl<const P: usize, const Q: usize, const R: usize, T: Ring> From<SparseMultivector<P, Q, R, T>>
+    for clifford::Multivector<P, Q, R, T>
+{
+    fn from(m: SparseMultivector<P, Q, R, T>) -> Self {
+        let mut mv = Self::zero();
+        for (mask, c) in m.terms() {
+            mv.set(mask, c);
+        }
+        mv
+    }
+}
+
+impl<T: Ring> From<::Multivector<T>> for SparseMultivector<3, 0, 0, T> {
+    fn from(m: ::Multivector<T>) -> Self {
+        SparseMultivector::from(clifford::Multivector::<3, 0, 0, T>::from(m))
+    }
+}
+
+impl<T: Ring> From<SparseMultivector<3, 0, 0, T>> for ::Multivector<T> {
+    fn from(m: SparseMultivector<3, 0, 0, T>) -> Self {
+        ::Multivector::from(clifford::Multivector::<3, 0, 0, T>::from(m))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use clifford::Sta;
+
+    type SparseSta = SparseMultivector<1, 3, 0>;
+    type Sparse16 = SparseMultivector<16, 0, 0>;
+
+    #[test]
+    fn test_matches_dense_products() {
+        let a = Sta::from_vector(&[1.0, 2.0, 0.0, 3.0]) + Sta::blade(0b0110, 2.0) + Sta::scalar(1.0);
+        let b = Sta::from_vector(&[2.0, 0.0, -1.0, 0.5]) + Sta::blade(0b1011, -1.0);
+        let (sa, sb) = (SparseSta::from(a.clone()), SparseSta::from(b.clone()));
+
+        assert_eq!(a.geop(&b), Sta::from(sa.geop(&sb)));
+        assert_eq!(a.wedgep(&b), Sta::from(sa.wedgep(&sb)));
+        assert_eq!(a.innerp(&b), Sta::from(sa.innerp(&sb)));
+        assert_eq!(a.reverse(), Sta::from(sa.reverse()));
+        assert_eq!(a.conjugate(), Sta::from(sa.conjugate()));
+    }
+
+    #[test]
+    fn test_high_dimension() {
+        let e0 = Sparse16::basis_vector(0);
+        let e15 = Sparse16::basis_vector(15);
+        let bivector = e0.wedgep(&e15);
+
+        assert_eq!(1, bivector.len());
+        assert_eq!(1.0, bivector.get(1 | 1 << 15));
+        assert_eq!(Sparse16::scalar(-1.0), bivector.geop(&bivector));
+        assert_eq!(-e15.clone(), e0.geop(&e15).geop(&e0));
+        assert!(e0.wedgep(&e0).is_empty());
+    }
+
+    #[test]
+    fn test_degenerate_metric() {
+        let e3 = SparseMultivector::<3, 0, 1>::basis_vector(3);
+
+        assert!(e3.geop(&e3).is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_basis_vector_outside_algebra() {
+        SparseMultivector::<3, 0, 0>::basis_vector(5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_blade_outside_algebra() {
+        SparseMultivector::<3, 0, 0>::blade(0b1000, 1.0);
+    }
+
+    #[test]
+    fn test_cancellation_drops_terms() {
+        let a = Sparse16::basis_vector(3) + Sparse16::scalar(2.0);
+
+        assert_eq!(Sparse16::scalar(2.0), a.clone() - Sparse16::basis_vector(3));
+        assert!((a.clone() - a).is_empty());
+    }
+
+    #[test]
+    fn test_dense_round_trip() {
+        let m = ::Multivector::new(1.0, 2.0, 0.0, 0.5, 0.0, -2.0, 1.5, 0.0);
+        let sparse = SparseMultivector::from(m);
+
+        assert_eq!(5, sparse.len());
+        assert_eq!(m, ::Multivector::from(sparse));
+    }
+}
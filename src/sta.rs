@@ -0,0 +1,331 @@
+//! Spacetime algebra Cl(1,3), in units where c = 1.
+//!
+//! The basis vectors of `clifford::Sta` are γ0, with γ0² = 1, and the
+//! spatial γ1, γ2, γ3, with γi² = -1. Relative to the observer γ0 a
+//! spacetime vector splits into a time and a relative vector expressed in
+//! the timelike bivectors σi = γi γ0, and the Faraday bivector splits into
+//! F = E + I B with I = γ0123.
+
+use clifford;
+use {GeometricProduct, InnerProduct, Inverse, Magnitude, RealField, Reverse, Ring, Vector};
+
+/// `clifford::Sta` over any scalar type.
+type Spacetime<T> = clifford::Multivector<1, 3, 0, T>;
+
+const PSEUDOSCALAR: usize = 0b1111;
+
+/// σi = γi γ0 for i = 1, 2, 3.
+fn relative_basis<T: Ring>(i: usize) -> Spacetime<T> {
+    Spacetime::basis_vector(i).geop(&Spacetime::basis_vector(0))
+}
+
+/// Reads the σi coefficients of a bivector.
+fn relative_vector<T: Ring>(mv: &Spacetime<T>) -> Vector<T> {
+    Vector::new(-mv.get(0b0011), -mv.get(0b0101), -mv.get(0b1001))
+}
+
+fn from_relative_vector<T: Ring>(v: &Vector<T>) -> Spacetime<T> {
+    relative_basis(1).scale(v.x) + relative_basis(2).scale(v.y) + relative_basis(3).scale(v.z)
+}
+
+/// A spacetime vector t γ0 + x γ1 + y γ2 + z γ3.
+#[derive(Clone, PartialEq, Debug)]
+pub struct FourVector<T = f64>(pub Spacetime<T>);
+
+/// The electromagnetic field bivector F = E + I B.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Faraday<T = f64>(pub Spacetime<T>);
+
+/// An even element of Cl(1,3) acting as a proper orthochronous Lorentz
+/// transformation through L X L̃.
+#[derive(Clone, PartialEq, Debug)]
+pub struct LorentzRotor<T = f64>(pub Spacetime<T>);
+
+impl<T: Ring> FourVector<T> {
+    pub fn new(t: T, x: T, y: T, z: T) -> Self {
+        FourVector(Spacetime::from_vector(&[t, x, y, z]))
+    }
+
+    /// The invariant interval v², positive for timelike vectors.
+    pub fn interval(&self) -> T {
+        self.0.innerp(&self.0).get(0)
+    }
+}
+
+impl<T: RealField> FourVector<T> {
+    /// The four-velocity γ(1, v) of a particle moving with velocity `v`,
+    /// or `None` unless |v| < 1.
+    pub fn from_velocity(v: &Vector<T>) -> Option<Self> {
+        let (zero, one) = (T::zero(), T::one());
+        Some(LorentzRotor::from_velocity(v)?.apply(&FourVector::new(one, zero, zero, zero)))
+    }
+
+    /// The proper time √(v²) along a timelike displacement, or `None` if it
+    /// is spacelike.
+    pub fn proper_time(&self) -> Option<T> {
+        let interval = self.interval();
+        if interval < T::zero() { None } else { Some(interval.sqrt()) }
+    }
+
+    /// Splits the vector into the time and relative position measured by
+    /// `observer`, a future-pointing timelike vector, or `None` if the
+    /// observer is spacelike, null or past-pointing.
+    pub fn split(&self, observer: &FourVector<T>) -> Option<(T, Vector<T>)> {
+        let v = LorentzRotor::to_observer(observer)?.inverse()?.apply(self);
+        Some((v.0.get(0b0001), Vector::new(v.0.get(0b0010), v.0.get(0b0100), v.0.get(0b1000))))
+    }
+
+    /// The velocity of this four-velocity as seen by `observer`.
+    pub fn relative_velocity(&self, observer: &FourVector<T>) -> Option<Vector<T>> {
+        let (t, x) = self.split(observer)?;
+        Some(Vector::new(x.x / t, x.y / t, x.z / t))
+    }
+}
+
+impl<T: Ring> Faraday<T> {
+    pub fn new(e: &Vector<T>, b: &Vector<T>) -> Self {
+        let i = Spacetime::blade(PSEUDOSCALAR, T::one());
+        Faraday(from_relative_vector(e) + i.geop(&from_relative_vector(b)))
+    }
+}
+
+impl<T: RealField> Faraday<T> {
+    /// The electric and magnetic fields measured by `observer`, or `None`
+    /// if the observer is spacelike, null or past-pointing.
+    pub fn split(&self, observer: &FourVector<T>) -> Option<(Vector<T>, Vector<T>)> {
+        let f = LorentzRotor::to_observer(observer)?.inverse()?.apply_field(self).0;
+        let g0 = Spacetime::basis_vector(0);
+        let i = Spacetime::blade(PSEUDOSCALAR, T::one());
+        let half = T::from_f64(0.5);
+
+        let e = (f.clone() - g0.geop(&f).geop(&g0)).scale(half);
+        let ib = (f.clone() + g0.geop(&f).geop(&g0)).scale(half);
+        Some((relative_vector(&e), relative_vector(&i.geop(&ib).scale(-T::one()))))
+    }
+
+    /// The Lorentz invariants (E² - B², E·B), read off F² = E² - B² + 2 I E·B.
+    pub fn invariants(&self) -> (T, T) {
+        let sq = self.0.geop(&self.0);
+        (sq.get(0), sq.get(PSEUDOSCALAR) / T::from_f64(2.0))
+    }
+}
+
+impl<T: Ring> LorentzRotor<T> {
+    pub fn identity() -> Self {
+        LorentzRotor(Spacetime::scalar(T::one()))
+    }
+
+    /// A spatial rotation, mapping each Euclidean bivector eij to σi σj.
+    pub fn rotation(r: &::Rotor<T>) -> Self {
+        let plane = |i: usize, j: usize| relative_basis(i).geop(&relative_basis(j));
+        LorentzRotor(Spacetime::scalar(r.scalar)
+            + plane(1, 2).scale(r.bivector.e12)
+            + plane(2, 3).scale(r.bivector.e23)
+            + plane(3, 1).scale(r.bivector.e31))
+    }
+
+    /// The rotor applying `self` first and `next` afterwards.
+    pub fn then(&self, next: &LorentzRotor<T>) -> LorentzRotor<T> {
+        LorentzRotor(next.0.geop(&self.0))
+    }
+
+    pub fn apply(&self, v: &FourVector<T>) -> FourVector<T> {
+        FourVector(self.0.geop(&v.0).geop(&self.0.reverse()))
+    }
+
+    pub fn apply_field(&self, f: &Faraday<T>) -> Faraday<T> {
+        Faraday(self.0.geop(&f.0).geop(&self.0.reverse()))
+    }
+}
+
+/// The general inverse in Cl(1,3), which is L̃ / (L L̃) for a rotor.
+impl<T: RealField> Inverse for LorentzRotor<T> {
+    fn inverse(&self) -> Option<Self> {
+        self.0.inverse().map(LorentzRotor)
+    }
+}
+
+impl<T: RealField> LorentzRotor<T> {
+    /// A pure boost with the given rapidity along `direction`, the rotor
+    /// cosh(φ/2) + sinh(φ/2) σ̂ generated by the timelike bivector σ̂. A zero
+    /// direction has no σ̂ and gives the identity.
+    pub fn boost(direction: &Vector<T>, rapidity: T) -> Self {
+        let mag = direction.mag();
+        if mag.is_zero() {
+            return LorentzRotor::identity();
+        }
+        let n = Vector::new(direction.x / mag, direction.y / mag, direction.z / mag);
+        let half = rapidity / T::from_f64(2.0);
+        LorentzRotor(Spacetime::scalar(half.cosh()) + from_relative_vector(&n).scale(half.sinh()))
+    }
+
+    /// The boost taking the rest frame to one moving with velocity `v`, or
+    /// `None` unless |v| < 1.
+    pub fn from_velocity(v: &Vector<T>) -> Option<Self> {
+        let speed = v.mag();
+        if speed >= T::one() {
+            return None;
+        }
+        Some(LorentzRotor::boost(v, speed.atanh()))
+    }
+
+    /// The pure boost taking γ0 onto the normalised `observer`, or `None`
+    /// if the observer is spacelike, null or past-pointing and so has no
+    /// rest frame reached by a boost.
+    pub fn to_observer(observer: &FourVector<T>) -> Option<Self> {
+        let tau = observer.proper_time()?;
+        if tau.is_zero() || observer.0.get(0b0001) <= T::zero() {
+            return None;
+        }
+        let (t, x) = (observer.0.get(0b0001) / tau, observer.0.get(0b0010) / tau);
+        let (y, z) = (observer.0.get(0b0100) / tau, observer.0.get(0b1000) / tau);
+        LorentzRotor::from_velocity(&Vector::new(x / t, y / t, z / t))
+    }
+
+    pub fn rapidity(&self) -> T {
+        let (zero, one) = (T::zero(), T::one());
+        let u = self.apply(&FourVector::new(one, zero, zero, zero));
+        u.0.get(0b0001).acosh()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use clifford::Sta;
+    use approx::assert_close;
+    use std::f64::consts::PI;
+
+    #[test]
+    fn test_four_velocity() {
+        let u = FourVector::from_velocity(&Vector::new(0.6, 0.0, 0.0)).unwrap();
+
+        let (t, x) = u.split(&FourVector::new(1.0, 0.0, 0.0, 0.0)).unwrap();
+
+        assert!((1.25 - t).abs() < 1e-12);
+        assert_close(Vector::new(0.75, 0.0, 0.0), x);
+        assert!((1.0 - u.interval()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_single_precision() {
+        let u = FourVector::<f32>::from_velocity(&Vector::new(0.6, 0.0, 0.0)).unwrap();
+        let (t, x) = u.split(&FourVector::new(1.0, 0.0, 0.0, 0.0)).unwrap();
+
+        assert!((1.25 - t).abs() < 1e-5);
+        assert!((0.75 - x.x).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_proper_time() {
+        assert_eq!(Some(4.0), FourVector::new(5.0, 3.0, 0.0, 0.0).proper_time());
+        assert_eq!(None, FourVector::new(1.0, 3.0, 0.0, 0.0).proper_time());
+    }
+
+    #[test]
+    fn test_velocity_addition() {
+        let boost = LorentzRotor::from_velocity(&Vector::new(0.5, 0.0, 0.0)).unwrap();
+        let composed = boost.then(&boost);
+
+        let u = composed.apply(&FourVector::new(1.0, 0.0, 0.0, 0.0));
+
+        assert_close(Vector::new(0.8, 0.0, 0.0), u.relative_velocity(&FourVector::new(1.0, 0.0, 0.0, 0.0)).unwrap());
+        assert!((composed.rapidity() - 2.0 * boost.rapidity()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_from_velocity_rejects_light_speed() {
+        assert_eq!(LorentzRotor::identity(), LorentzRotor::from_velocity(&Vector::new(0.0, 0.0, 0.0)).unwrap());
+        assert_eq!(None, LorentzRotor::from_velocity(&Vector::new(0.6, 0.8, 0.0)));
+        assert_eq!(None, LorentzRotor::from_velocity(&Vector::new(2.0, 0.0, 0.0)));
+        assert_eq!(None, FourVector::from_velocity(&Vector::new(0.0, 0.0, 1.0)));
+    }
+
+    #[test]
+    fn test_lorentz_rotor_inverse() {
+        let boost = LorentzRotor::from_velocity(&Vector::new(0.3, -0.2, 0.5)).unwrap();
+        let v = FourVector::new(2.0, 1.0, 0.0, -1.0);
+
+        assert_close(v.0.clone(), boost.inverse().unwrap().apply(&boost.apply(&v)).0);
+        assert_eq!(None, LorentzRotor(Sta::zero()).inverse());
+    }
+
+    #[test]
+    fn test_boost_along_zero_direction() {
+        assert_eq!(LorentzRotor::identity(), LorentzRotor::boost(&Vector::new(0.0, 0.0, 0.0), 0.5));
+    }
+
+    #[test]
+    fn test_split_relative_to_moving_observer() {
+        let observer = FourVector::from_velocity(&Vector::new(0.0, 0.6, 0.0)).unwrap();
+        let event = LorentzRotor::from_velocity(&Vector::new(0.0, 0.6, 0.0)).unwrap().apply(&FourVector::new(2.0, 1.0, 0.0, 0.0));
+
+        let (t, x) = event.split(&observer).unwrap();
+
+        assert!((2.0 - t).abs() < 1e-12);
+        assert_close(Vector::new(1.0, 0.0, 0.0), x);
+    }
+
+    #[test]
+    fn test_split_rejects_observers_without_rest_frame() {
+        let event = FourVector::new(2.0, 1.0, 0.0, 0.0);
+        let field = Faraday::new(&Vector::new(1.0, 0.0, 0.0), &Vector::new(0.0, 1.0, 0.0));
+
+        let observers = [
+            FourVector::new(1.0, 3.0, 0.0, 0.0),
+            FourVector::new(1.0, 1.0, 0.0, 0.0),
+            FourVector::new(-1.0, 0.0, 0.0, 0.0)
+        ];
+        for observer in &observers {
+            assert!(LorentzRotor::to_observer(observer).is_none());
+            assert_eq!(None, event.split(observer));
+            assert_eq!(None, field.split(observer));
+        }
+    }
+
+    #[test]
+    fn test_spatial_rotation() {
+        let rotor = LorentzRotor::rotation(&::Rotor::from_axis_angle(&Vector::new(0.0, 0.0, 1.0), PI / 2.0));
+
+        let (_, x) = rotor.apply(&FourVector::new(1.0, 1.0, 0.0, 0.0)).split(&FourVector::new(1.0, 0.0, 0.0, 0.0)).unwrap();
+
+        assert_close(Vector::new(0.0, 1.0, 0.0), x);
+    }
+
+    #[test]
+    fn test_faraday_split() {
+        let e = Vector::new(1.0, 2.0, 3.0);
+        let b = Vector::new(-1.0, 0.5, 2.0);
+
+        let (e2, b2) = Faraday::new(&e, &b).split(&FourVector::new(1.0, 0.0, 0.0, 0.0)).unwrap();
+
+        assert_close(e, e2);
+        assert_close(b, b2);
+    }
+
+    #[test]
+    fn test_faraday_boost() {
+        let field = Faraday::new(&Vector::new(0.0, 1.0, 0.0), &Vector::new(0.0, 0.0, 0.0));
+        let observer = FourVector::from_velocity(&Vector::new(0.6, 0.0, 0.0)).unwrap();
+
+        let (e, b) = field.split(&observer).unwrap();
+
+        assert_close(Vector::new(0.0, 1.25, 0.0), e);
+        assert_close(Vector::new(0.0, 0.0, -0.75), b);
+    }
+
+    #[test]
+    fn test_faraday_invariants() {
+        let field = Faraday::new(&Vector::new(1.0, 2.0, 0.0), &Vector::new(0.0, 1.0, 1.0));
+        let boost = LorentzRotor::from_velocity(&Vector::new(0.3, -0.2, 0.5)).unwrap();
+
+        let (s, p) = field.invariants();
+        let (s2, p2) = boost.apply_field(&field).invariants();
+
+        assert!((3.0 - s).abs() < 1e-12);
+        assert!((2.0 - p).abs() < 1e-12);
+        assert!((s - s2).abs() < 1e-9);
+        assert!((p - p2).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,230 @@
+//! Euclidean vectors and blades in N dimensions, backed by the generic
+//! `clifford::Multivector<N, 0, 0>` for anything above grade one.
+
+use clifford::Multivector;
+use {Angle, Conjugate, GradeInvolution, InnerProduct, Magnitude, RealField, Reverse, Ring, Scalar, Vector, WedgeProduct};
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct VectorN<const N: usize, T = f64> {
+    pub components: [T; N]
+}
+
+/// A k-vector of N-dimensional Euclidean space, as built by wedging
+/// `VectorN`s together.
+#[derive(Clone, PartialEq, Debug)]
+pub struct BladeN<const N: usize, T = f64>(pub Multivector<N, 0, 0, T>);
+
+impl<const N: usize, T: Ring> VectorN<N, T> {
+    pub fn new(components: [T; N]) -> Self {
+        VectorN {
+            components
+        }
+    }
+
+    pub fn zero() -> Self {
+        VectorN::new([T::zero(); N])
+    }
+
+    /// The `i`th unit basis vector, counting from zero.
+    pub fn basis(i: usize) -> Self {
+        let mut v = VectorN::zero();
+        v.components[i] = T::one();
+        v
+    }
+
+    fn to_multivector(self) -> Multivector<N, 0, 0, T> {
+        Multivector::from_vector(&self.components)
+    }
+}
+
+impl<const N: usize, T: Ring> BladeN<N, T> {
+    /// The grade of the blade, or `None` for the zero blade.
+    pub fn grade(&self) -> Option<usize> {
+        self.0.coeffs().iter().enumerate()
+            .find(|&(_, c)| !c.is_zero())
+            .map(|(mask, _)| mask.count_ones() as usize)
+    }
+
+    /// The coefficient of the basis blade with the given bitmask.
+    pub fn get(&self, mask: usize) -> T {
+        self.0.get(mask)
+    }
+}
+
+impl<const N: usize, T: Ring> Reverse for VectorN<N, T> {
+    fn reverse(&self) -> Self {
+        *self
+    }
+}
+
+impl<const N: usize, T: Ring> GradeInvolution for VectorN<N, T> {
+    fn involute(&self) -> Self {
+        let mut v = *self;
+        for c in v.components.iter_mut() {
+            *c = -*c;
+        }
+        v
+    }
+}
+
+impl<const N: usize, T: Ring> Conjugate for VectorN<N, T> {
+    fn conjugate(&self) -> Self {
+        self.involute()
+    }
+}
+
+impl<const N: usize, T: Ring> Reverse for BladeN<N, T> {
+    fn reverse(&self) -> Self {
+        BladeN(self.0.reverse())
+    }
+}
+
+impl<const N: usize, T: Ring> GradeInvolution for BladeN<N, T> {
+    fn involute(&self) -> Self {
+        BladeN(self.0.involute())
+    }
+}
+
+impl<const N: usize, T: Ring> Conjugate for BladeN<N, T> {
+    fn conjugate(&self) -> Self {
+        BladeN(self.0.conjugate())
+    }
+}
+
+impl<const N: usize, T: RealField> Magnitude<T> for VectorN<N, T> {
+    fn mag(&self) -> T {
+        self.components.iter().fold(T::zero(), |acc, c| acc + *c * *c).sqrt()
+    }
+}
+
+/// The k-volume of the parallelotope spanned by the blade's factors.
+impl<const N: usize, T: RealField> Magnitude<T> for BladeN<N, T> {
+    fn mag(&self) -> T {
+        self.0.coeffs().iter().fold(T::zero(), |acc, c| acc + *c * *c).sqrt()
+    }
+}
+
+impl<const N: usize, T: RealField> Angle<VectorN<N, T>, T> for VectorN<N, T> {
+    fn angle(&self, other: &VectorN<N, T>) -> T {
+        (self.innerp(other).value / (self.mag() * other.mag())).acos()
+    }
+}
+
+impl<const N: usize, T: Ring> InnerProduct for VectorN<N, T> {
+    type Output = Scalar<T>;
+
+    fn innerp(&self, other: &VectorN<N, T>) -> Scalar<T> {
+        Scalar {
+            value: self.components.iter().zip(other.components.iter()).fold(T::zero(), |acc, (a, b)| acc + *a * *b)
+        }
+    }
+}
+
+impl<const N: usize, T: Ring> WedgeProduct for VectorN<N, T> {
+    type Output = BladeN<N, T>;
+
+    fn wedgep(&self, other: &VectorN<N, T>) -> BladeN<N, T> {
+        BladeN(self.to_multivector().wedgep(&other.to_multivector()))
+    }
+}
+
+impl<const N: usize, T: Ring> WedgeProduct<VectorN<N, T>> for BladeN<N, T> {
+    type Output = BladeN<N, T>;
+
+    fn wedgep(&self, other: &VectorN<N, T>) -> BladeN<N, T> {
+        BladeN(self.0.wedgep(&other.to_multivector()))
+    }
+}
+
+impl<const N: usize, T: Ring> WedgeProduct for BladeN<N, T> {
+    type Output = BladeN<N, T>;
+
+    fn wedgep(&self, other: &BladeN<N, T>) -> BladeN<N, T> {
+        BladeN(self.0.wedgep(&other.0))
+    }
+}
+
+impl<const N: usize, T: Ring> From<VectorN<N, T>> for BladeN<N, T> {
+    fn from(v: VectorN<N, T>) -> Self {
+        BladeN(v.to_multivector())
+    }
+}
+
+impl<T: Ring> From<Vector<T>> for VectorN<3, T> {
+    fn from(v: Vector<T>) -> Self {
+        VectorN::new([v.x, v.y, v.z])
+    }
+}
+
+impl<T: Ring> From<VectorN<3, T>> for Vector<T> {
+    fn from(v: VectorN<3, T>) -> Self {
+        Vector::new(v.components[0], v.components[1], v.components[2])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_vectorn_mag_and_angle() {
+        let vec1 = VectorN::new([1.0, 1.0, 1.0, 1.0]);
+        let vec2 = VectorN::new([1.0, -1.0, 1.0, -1.0]);
+
+        assert_eq!(2.0, vec1.mag());
+        assert_eq!(0.0, vec1.innerp(&vec2).value);
+        assert_eq!(std::f64::consts::PI / 2.0, vec1.angle(&vec2));
+    }
+
+    #[test]
+    fn test_wedge_grades() {
+        let e = |i| VectorN::<6>::basis(i);
+
+        let blade = e(0).wedgep(&e(2)).wedgep(&e(5));
+
+        assert_eq!(Some(3), blade.grade());
+        assert_eq!(1.0, blade.get(0b100101));
+        assert_eq!(None, e(1).wedgep(&e(1)).grade());
+    }
+
+    #[test]
+    fn test_four_volume() {
+        let a = VectorN::new([2.0, 0.0, 0.0, 0.0]);
+        let b = VectorN::new([1.0, 3.0, 0.0, 0.0]);
+        let c = VectorN::new([4.0, 1.0, 1.0, 0.0]);
+        let d = VectorN::new([7.0, 5.0, 2.0, 0.5]);
+
+        assert_eq!(3.0, a.wedgep(&b).wedgep(&c).wedgep(&d).mag());
+    }
+
+    #[test]
+    fn test_area_matches_sine() {
+        let a = VectorN::new([1.0, 2.0, 0.0, -1.0, 3.0, 0.5, 0.0, 1.0]);
+        let b = VectorN::new([0.0, 1.0, 1.0, 2.0, -1.0, 0.0, 2.0, 0.0]);
+
+        let area = a.mag() * b.mag() * a.angle(&b).sin();
+
+        assert!((area - a.wedgep(&b).mag()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_blade_involutions() {
+        let e = |i| VectorN::<5>::basis(i);
+
+        let blade = e(0).wedgep(&e(3));
+
+        assert_eq!(e(3).wedgep(&e(0)), blade.reverse());
+        assert_eq!(blade, blade.involute());
+        assert_eq!(BladeN::from(e(4).involute()), BladeN::from(e(4)).involute());
+    }
+
+    #[test]
+    fn test_vector_conversion() {
+        let v = Vector::new(1.0, 2.0, 3.0);
+        let n = VectorN::from(v);
+
+        assert_eq!(v.mag(), n.mag());
+        assert_eq!(v, Vector::from(n));
+    }
+}